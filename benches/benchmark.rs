@@ -1,15 +1,17 @@
+use std::collections::HashMap;
 use std::hint::black_box;
-use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
 use uuid::Uuid;
+use asteroids_server::collections::slot_map::SlotMap;
 use asteroids_server::game::GameManager;
 use asteroids_server::networking::router::MovePayload;
 
 fn generate_game() -> GameManager {
-    // seed fixa: workload reproduzível entre execuções; já spawna max_asteroids (16)
+    // seed fixa: workload reproduzível entre execuções; já spawna a primeira onda
     let mut game = GameManager::with_seed(42);
 
-    for _ in 0..255 {
-        let id = Uuid::new_v4();
+    for i in 0..255 {
+        let id = Uuid::from_u128(i + 1);
         let _ = game.players.add_player(&id);
 
         let fire = MovePayload { thrust: true, left: false, right: false, fire: true };
@@ -25,7 +27,8 @@ fn bench_tick(c: &mut Criterion) {
             generate_game,
             |mut game| {
                 for _ in 0..500 {
-                    black_box(game.tick());
+                    game.tick();
+                    black_box(&game);
                 }
             },
             BatchSize::SmallInput,
@@ -33,5 +36,80 @@ fn bench_tick(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_tick);
-criterion_main!(benches);
\ No newline at end of file
+/// Mesma carga forçando o caminho serial, pra comparar com o paralelo.
+#[cfg(feature = "parallel")]
+fn bench_tick_serial(c: &mut Criterion) {
+    c.bench_function("500 ticks - max entities (serial)", |b| {
+        b.iter_batched(
+            || {
+                let mut game = generate_game();
+                game.set_parallel(false);
+                game
+            },
+            |mut game| {
+                for _ in 0..500 {
+                    game.tick();
+                    black_box(&game);
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+/// Snapshot com o campo cheio de balas; a vazão em bytes mostra o tamanho.
+fn bench_snapshot(c: &mut Criterion) {
+    let mut game = generate_game();
+    for _ in 0..60 {
+        game.tick();
+    }
+    let size = game.get_game_state().len();
+
+    let mut group = c.benchmark_group("snapshot");
+    group.throughput(Throughput::Bytes(size as u64));
+    group.bench_function("get_game_state", |b| b.iter(|| black_box(game.get_game_state())));
+    group.finish();
+}
+
+/// Entra e sai de 2048 entidades: slot map com id u32 contra o
+/// `HashMap<Uuid, _>` de antes.
+fn bench_ids(c: &mut Criterion) {
+    const N: usize = 2048;
+    let mut group = c.benchmark_group("entity ids");
+
+    group.bench_function("SlotMap insert/get/remove", |b| {
+        b.iter(|| {
+            let mut map = SlotMap::new();
+            let ids: Vec<_> = (0..N).map(|i| map.insert(i as f32)).collect();
+            let sum: f32 = ids.iter().filter_map(|id| map.get(*id)).sum();
+            for id in ids {
+                map.remove(id);
+            }
+            black_box(sum)
+        })
+    });
+
+    // uuids gerados fora da medição: só o custo do mapa entra
+    let uuids: Vec<Uuid> = (0..N as u128).map(|i| Uuid::from_u128(i.wrapping_mul(0x9e37_79b9_7f4a_7c15))).collect();
+    group.bench_function("HashMap<Uuid> insert/get/remove", |b| {
+        b.iter(|| {
+            let mut map = HashMap::new();
+            for (i, id) in uuids.iter().enumerate() {
+                map.insert(*id, i as f32);
+            }
+            let sum: f32 = uuids.iter().filter_map(|id| map.get(id)).sum();
+            for id in uuids.iter() {
+                map.remove(id);
+            }
+            black_box(sum)
+        })
+    });
+
+    group.finish();
+}
+
+#[cfg(not(feature = "parallel"))]
+criterion_group!(benches, bench_tick, bench_snapshot, bench_ids);
+#[cfg(feature = "parallel")]
+criterion_group!(benches, bench_tick, bench_tick_serial, bench_snapshot, bench_ids);
+criterion_main!(benches);
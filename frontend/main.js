@@ -14,6 +14,7 @@ let pingIntervalId = null;
 let playerWasSeen = false;
 
 const network = new Network();
+const WEAPONS = ["cannon", "laser", "mine", "missile"];
 
 // -----------------------------
 // 🎮 Entrada do jogador
@@ -25,6 +26,10 @@ function handleInput(evt, isDown) {
   if (key === "d") input.right = isDown;
   if (key === "w") input.forward = isDown;
 
+  // 1-4 trocam de arma (mesma ordem do servidor)
+  const weapon = WEAPONS[Number(key) - 1];
+  if (isDown && weapon) network.sendChooseWeapon(weapon);
+
   if (key === " ") {
     input.fire = isDown;
     evt.preventDefault();
@@ -46,12 +51,14 @@ function gameLoop() {
   const players = latestGameState["Players"] || [];
   const bullets = latestGameState["Bullets"] || [];
   const asteroids = latestGameState["Asteroids"] || [];
+  const powerups = latestGameState["PowerUps"] || [];
+  const ufos = latestGameState["Ufos"] || [];
 
   const player = players.find((p) => p.id === localPlayerId);
 
   if (player) {
     playerWasSeen = true;
-    drawWorld(ctx, canvas, player, players, bullets, asteroids);
+    drawWorld(ctx, canvas, player, players, bullets, asteroids, powerups, network.get_beams(), ufos);
   } else if (playerWasSeen) {
     clearInterval(pingIntervalId);
     pingIntervalId = null;
@@ -90,7 +97,13 @@ async function startGame() {
   latestGameState = { Players: [], Bullets: [], Asteroids: [] };
   showScreen("connecting");
 
-  network.openSocket();
+  // a sessão (socket) sobrevive entre partidas; só reabre se caiu
+  if (!network.isSocketOpen()) {
+    network.openSocket();
+  }
+  await waitFor(() => network.get_session_id());
+
+  network.sendConnect();
   await waitFor(() => network.get_client_id());
   localPlayerId = network.get_client_id();
 
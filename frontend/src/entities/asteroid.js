@@ -1,27 +1,42 @@
 export class Asteroid {
-  constructor(id, x = 0, y = 0, radius = 5) {
+  constructor(id, x = 0, y = 0, radius = 5, shape = null, angle = 0) {
     this.id = id;
     this.x = x;
     this.y = y;
     this.radius = radius;
+    this.angle = angle;
+    // contorno do servidor ([[x, y], ...] em volta do centro); sem ele, pentágono
+    this.shape = shape;
     this.color = "#aaa";
   }
 
   draw(ctx) {
-    const sides = 5;
-    const step = (Math.PI * 2) / sides;
-
     ctx.strokeStyle = this.color;
     ctx.lineWidth = 2;
     ctx.beginPath();
 
-    for (let i = 0; i <= sides; i++) {
-      const angle = i * step;
-      const x = this.x + Math.cos(angle) * this.radius;
-      const y = this.y + Math.sin(angle) * this.radius;
+    if (Array.isArray(this.shape) && this.shape.length > 2) {
+      // contorno girado pelo ângulo do snapshot, igual à colisão no servidor
+      const cos = Math.cos(this.angle);
+      const sin = Math.sin(this.angle);
+      this.shape.forEach(([px, py], i) => {
+        const x = this.x + px * cos - py * sin;
+        const y = this.y + px * sin + py * cos;
+        if (i === 0) ctx.moveTo(x, y);
+        else ctx.lineTo(x, y);
+      });
+    } else {
+      const sides = 5;
+      const step = (Math.PI * 2) / sides;
+
+      for (let i = 0; i <= sides; i++) {
+        const angle = i * step;
+        const x = this.x + Math.cos(angle) * this.radius;
+        const y = this.y + Math.sin(angle) * this.radius;
 
-      if (i === 0) ctx.moveTo(x, y);
-      else ctx.lineTo(x, y);
+        if (i === 0) ctx.moveTo(x, y);
+        else ctx.lineTo(x, y);
+      }
     }
 
     ctx.closePath();
@@ -1,22 +1,52 @@
 export class Bullet {
-  constructor(id, x, y, angle, playerId) {
+  constructor(id, x, y, angle, playerId, weapon = "cannon") {
     this.id = id;
     this.x = x;
     this.y = y;
     this.angle = angle;
     this.playerId = playerId;
+    this.weapon = weapon;
   }
 
   draw(ctx, isSelf = false) {
-    ctx.fillStyle = isSelf ? "lime" : "red";
+    const color = isSelf ? "lime" : "red";
+    ctx.fillStyle = color;
+    ctx.strokeStyle = color;
 
     ctx.save();
     ctx.translate(this.x, this.y);
+    ctx.rotate(this.angle);
 
     ctx.beginPath();
-    ctx.arc(0, 0, 3, 0, Math.PI * 2);
-    ctx.fill();
+    if (this.weapon === "mine") {
+      // anel com miolo, pra não confundir com bala parada
+      ctx.lineWidth = 2;
+      ctx.arc(0, 0, 10, 0, Math.PI * 2);
+      ctx.stroke();
+      ctx.beginPath();
+      ctx.arc(0, 0, 3, 0, Math.PI * 2);
+      ctx.fill();
+    } else if (this.weapon === "missile") {
+      ctx.moveTo(8, 0);
+      ctx.lineTo(-6, -4);
+      ctx.lineTo(-6, 4);
+      ctx.closePath();
+      ctx.fill();
+    } else {
+      ctx.arc(0, 0, 3, 0, Math.PI * 2);
+      ctx.fill();
+    }
 
     ctx.restore();
   }
 }
+
+// raio do laser, visto por um instante (vem nos eventos)
+export function drawBeam(ctx, x, y, angle, length, isSelf = false) {
+  ctx.strokeStyle = isSelf ? "rgba(0, 255, 0, 0.8)" : "rgba(255, 60, 60, 0.8)";
+  ctx.lineWidth = 2;
+  ctx.beginPath();
+  ctx.moveTo(x, y);
+  ctx.lineTo(x + Math.cos(angle) * length, y + Math.sin(angle) * length);
+  ctx.stroke();
+}
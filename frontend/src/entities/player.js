@@ -1,9 +1,11 @@
 export class Player {
-  constructor(id, x = 0, y = 0, angle = 0) {
+  constructor(id, x = 0, y = 0, angle = 0, health = null, shield = 0) {
     this.id = id;
     this.x = x;
     this.y = y;
     this.angle = angle;
+    this.health = health;
+    this.shield = shield;
     this.vx = 0;
     this.vy = 0;
     this.turnSpeed = 0.05;
@@ -27,5 +29,22 @@ export class Player {
     ctx.fill();
 
     ctx.restore();
+
+    if (this.shield > 0) {
+      ctx.strokeStyle = "rgba(80, 160, 255, 0.7)";
+      ctx.beginPath();
+      ctx.arc(this.x, this.y, size + 6, 0, Math.PI * 2);
+      ctx.stroke();
+    }
+
+    // barra de vida embaixo da nave (o servidor manda em pontos, máximo 100 no padrão)
+    if (this.health !== null) {
+      const width = 24;
+      const filled = Math.max(0, Math.min(1, this.health / 100)) * width;
+      ctx.fillStyle = "#400";
+      ctx.fillRect(this.x - width / 2, this.y + size + 6, width, 3);
+      ctx.fillStyle = "#0c0";
+      ctx.fillRect(this.x - width / 2, this.y + size + 6, filled, 3);
+    }
   }
 }
@@ -0,0 +1,32 @@
+// letra e cor por tipo (mesmos nomes do servidor)
+const LOOK = {
+  rapid_fire: ["R", "#ff0"],
+  spread_shot: ["T", "#f80"],
+  shield: ["S", "#4af"],
+  speed_boost: ["V", "#0f8"],
+};
+
+export class PowerUp {
+  constructor(id, x = 0, y = 0, kind = "") {
+    this.id = id;
+    this.x = x;
+    this.y = y;
+    this.kind = kind;
+  }
+
+  draw(ctx) {
+    const [letter, color] = LOOK[this.kind] || ["?", "#fff"];
+
+    ctx.strokeStyle = color;
+    ctx.fillStyle = color;
+    ctx.lineWidth = 2;
+    ctx.beginPath();
+    ctx.arc(this.x, this.y, 12, 0, Math.PI * 2);
+    ctx.stroke();
+
+    ctx.font = "12px monospace";
+    ctx.textAlign = "center";
+    ctx.textBaseline = "middle";
+    ctx.fillText(letter, this.x, this.y);
+  }
+}
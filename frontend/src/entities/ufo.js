@@ -0,0 +1,21 @@
+// disco voador inimigo: cúpula em cima de um casco achatado
+export class Ufo {
+  constructor(id, x = 0, y = 0) {
+    this.id = id;
+    this.x = x;
+    this.y = y;
+  }
+
+  draw(ctx) {
+    ctx.strokeStyle = "#f0f";
+    ctx.lineWidth = 2;
+
+    ctx.beginPath();
+    ctx.ellipse(this.x, this.y, 16, 6, 0, 0, Math.PI * 2);
+    ctx.stroke();
+
+    ctx.beginPath();
+    ctx.arc(this.x, this.y - 3, 7, Math.PI, 0);
+    ctx.stroke();
+  }
+}
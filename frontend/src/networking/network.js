@@ -6,6 +6,12 @@ export class Network {
     this.clientId = null;
     this.lastPing = null;
     this.socket = null;
+    // aviso do servidor (admin ou desligamento) ainda não mostrado
+    this.notice = null;
+    // contorno de cada asteroide (id -> [[x, y], ...]), vem só no spawn
+    this.shapes = {};
+    // lasers recentes ({ owner, x, y, angle, length, until })
+    this.beams = [];
 
     //this.url = "localhost:8080";
     this.url = "asteroids-server-ampj.onrender.com";
@@ -27,6 +33,8 @@ export class Network {
     this.sessionId = null;
     this.clientId = null;
     this.gameState = {};
+    this.shapes = {};
+    this.beams = [];
 
     const wsProtocol = this.url.startsWith("localhost") ? "ws" : "wss";
     this.socket = new WebSocket(`${wsProtocol}://${this.url}/ws`);
@@ -60,6 +68,34 @@ export class Network {
           return;
         }
 
+        if (msg.type === "asteroid_shapes") {
+          for (const s of msg.shapes) this.shapes[s.id] = s.points;
+          return;
+        }
+
+        if (msg.type === "server_notice") {
+          this.notice = msg.message;
+          console.log("Aviso do servidor:", msg.message);
+          return;
+        }
+
+        if (msg.type === "server_shutdown") {
+          this.notice = "Servidor reiniciando";
+          console.log("Servidor desligando; reconectar:", msg.reconnect);
+          if (msg.reconnect) this.url = msg.reconnect;
+          return;
+        }
+
+        for (const event of msg.Events || []) {
+          if (event.type === "idle_warning" && event.id === this.clientId) {
+            this.notice = `Sem movimento: saindo em ${Math.ceil(event.remove_in)}s`;
+          }
+          if (event.type === "asteroid_spawned") this.shapes[event.id] = event.shape;
+          if (event.type === "asteroid_destroyed") delete this.shapes[event.id];
+          if (event.type === "laser_fired") this.beams.push({ ...event, until: Date.now() + 120 });
+        }
+
+        for (const a of msg.Asteroids || []) a.shape = this.shapes[a.id];
         this.gameState = msg;
       } catch (e) {
         console.error("Erro ao parsear estado do jogo:", e, data);
@@ -94,6 +130,12 @@ export class Network {
     return this.gameInfo;
   }
 
+  get_beams() {
+    const now = Date.now();
+    this.beams = this.beams.filter((b) => b.until > now);
+    return this.beams;
+  }
+
   get_session_id() {
     return this.sessionId;
   }
@@ -111,6 +153,11 @@ export class Network {
     return this.lastPing;
   }
 
+  sendChooseWeapon(weapon) {
+    if (!this.isSocketOpen() || !this.clientId) return;
+    this.socket.send(JSON.stringify({ action: "choose_weapon", weapon }));
+  }
+
   sendMove(move) {
     if (!this.isSocketOpen() || !this.clientId) return;
     if (!move.left && !move.right && !move.forward && !move.fire) return;
@@ -56,6 +56,19 @@ export function drawHUD(ctx, canvas, players, asteroids, localPlayerId, lastPing
   ctx.lineWidth = 1;
   ctx.strokeRect(mx, my, MINIMAP_SIZE, MINIMAP_SIZE);
 
+  const weapon = self && self.weapon;
+  if (weapon) {
+    let status = weapon.kind;
+    if (weapon.ammo !== null) status += ` ${weapon.ammo}`;
+    if (weapon.heat > 0) status += ` ${Math.round(weapon.heat)}°`;
+    if (weapon.overheated) status += " (quente)";
+
+    ctx.font = "16px 'Courier New'";
+    ctx.fillStyle = weapon.overheated ? "#f44" : "#0f0";
+    ctx.textAlign = "left";
+    ctx.fillText(`Arma: ${status}`, 16, 52);
+  }
+
   if (lastPing !== null) {
     ctx.font = "16px 'Courier New'";
     ctx.fillStyle = "#0f0";
@@ -1,6 +1,8 @@
 import { WORLD_SIZE } from "../constants.js";
 import { Player } from "../entities/player.js";
-import { Bullet } from "../entities/bullet.js";
+import { PowerUp } from "../entities/powerup.js";
+import { Ufo } from "../entities/ufo.js";
+import { Bullet, drawBeam } from "../entities/bullet.js";
 import { Asteroid } from "../entities/asteroid.js";
 
 export function warpPosition(px, py, ox, oy) {
@@ -47,7 +49,7 @@ function drawBackground(ctx, cameraX, cameraY, width, height) {
   }
 }
 
-export function drawWorld(ctx, canvas, player, players, bullets, asteroids) {
+export function drawWorld(ctx, canvas, player, players, bullets, asteroids, powerups = [], beams = [], ufos = []) {
   const cameraX = player.x - canvas.width / 2;
   const cameraY = player.y - canvas.height / 2;
 
@@ -58,9 +60,10 @@ export function drawWorld(ctx, canvas, player, players, bullets, asteroids) {
 
   if (Array.isArray(players)) {
     players.forEach((p) => {
+      if (p.is_destroyed) return; // esperando a volta
       const warped = warpPosition(player.x, player.y, p.x, p.y);
       if (isVisible(warped, cameraX, cameraY, canvas.width, canvas.height)) {
-        new Player(p.id, warped.x, warped.y, p.angle).draw(ctx, p.id === player.id);
+        new Player(p.id, warped.x, warped.y, p.angle, p.health, p.shield).draw(ctx, p.id === player.id);
       }
     });
   }
@@ -69,7 +72,7 @@ export function drawWorld(ctx, canvas, player, players, bullets, asteroids) {
     bullets.forEach((b) => {
       const warped = warpPosition(player.x, player.y, b.x, b.y);
       if (isVisible(warped, cameraX, cameraY, canvas.width, canvas.height)) {
-        new Bullet(b.id, warped.x, warped.y, b.angle, b.player_id).draw(ctx, b.player_id === player.id);
+        new Bullet(b.id, warped.x, warped.y, b.angle, b.player_id, b.weapon).draw(ctx, b.player_id === player.id);
       }
     });
   }
@@ -78,10 +81,29 @@ export function drawWorld(ctx, canvas, player, players, bullets, asteroids) {
     asteroids.forEach((a) => {
       const warped = warpPosition(player.x, player.y, a.x, a.y);
       if (isVisible(warped, cameraX, cameraY, canvas.width, canvas.height)) {
-        new Asteroid(a.id, warped.x, warped.y, a.radius).draw(ctx);
+        new Asteroid(a.id, warped.x, warped.y, a.radius, a.shape, a.angle).draw(ctx);
       }
     });
   }
 
+  beams.forEach((b) => {
+    const warped = warpPosition(player.x, player.y, b.x, b.y);
+    drawBeam(ctx, warped.x, warped.y, b.angle, b.length, b.owner === player.id);
+  });
+
+  powerups.forEach((u) => {
+    const warped = warpPosition(player.x, player.y, u.x, u.y);
+    if (isVisible(warped, cameraX, cameraY, canvas.width, canvas.height)) {
+      new PowerUp(u.id, warped.x, warped.y, u.kind).draw(ctx);
+    }
+  });
+
+  ufos.forEach((u) => {
+    const warped = warpPosition(player.x, player.y, u.x, u.y);
+    if (isVisible(warped, cameraX, cameraY, canvas.width, canvas.height)) {
+      new Ufo(u.id, warped.x, warped.y).draw(ctx);
+    }
+  });
+
   ctx.restore();
 }
@@ -0,0 +1,65 @@
+//! Re-simula uma partida gravada (`replay.path` na config) e imprime os
+//! snapshots, um por linha, iguais aos que o servidor mandou.
+//!
+//!     cargo run --bin replay -- partida.replay [--from N] [--to N] [--every N]
+
+use asteroids_server::replay::Replay;
+
+struct Args {
+    path: String,
+    from: u64,
+    to: u64,
+    every: u64,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = std::env::args().skip(1);
+    let mut parsed = Args { path: String::new(), from: 1, to: u64::MAX, every: 1 };
+
+    while let Some(arg) = args.next() {
+        let mut number = |name: &str| -> Result<u64, String> {
+            args.next()
+                .and_then(|v| v.parse().ok())
+                .ok_or_else(|| format!("{} precisa de um número", name))
+        };
+
+        match arg.as_str() {
+            "--from" => parsed.from = number("--from")?,
+            "--to" => parsed.to = number("--to")?,
+            "--every" => parsed.every = number("--every")?.max(1),
+            _ if arg.starts_with("--") => return Err(format!("opção desconhecida: {}", arg)),
+            _ => parsed.path = arg,
+        }
+    }
+
+    if parsed.path.is_empty() {
+        return Err("uso: replay <arquivo> [--from N] [--to N] [--every N]".to_string());
+    }
+    Ok(parsed)
+}
+
+fn main() {
+    let result = parse_args().and_then(|args| {
+        let replay = Replay::from_file(&args.path)?;
+        eprintln!("seed {} · modo {} · {} ticks · {} entradas", replay.seed, replay.config.mode.name, replay.ticks, replay.records.len());
+
+        let game = replay.play(|game| {
+            let tick = game.tick_count();
+            // o snapshot esvazia a fila de eventos: tira sempre, imprime só o pedido
+            let state = game.get_game_state();
+            if (args.from..=args.to).contains(&tick) && (tick - args.from) % args.every == 0 {
+                println!("{}", state);
+            }
+        });
+
+        for (id, score, _) in game.scoreboard() {
+            eprintln!("{} {}", id, score);
+        }
+        Ok(())
+    });
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
@@ -1,142 +1,187 @@
-use std::collections::HashMap;
-use uuid::Uuid;
-
+use crate::config::GameConfig;
 use crate::entities::asteroid::{Asteroid, AsteroidType};
-use crate::types::{TICK_RATE, WORLD_SIZE};
-use crate::entities::traits::warp_object::WarpObject;
-use crate::entities::hitbox::HitBox;
-use crate::entities::traits::collision_object::CollisionObject;
+use crate::entities::hitbox::{EntityKind, Shape};
+use crate::events::GameEvent;
+use crate::types::{ClientId, EntityId};
+use crate::world::World;
+use crate::world::components::{Transform, Velocity};
+use std::f32::consts::{FRAC_PI_2, TAU};
+use std::ops::Range;
 use rand::Rng;
 use rand::SeedableRng;
 use rand::rngs::StdRng;
 
+/// Regras dos asteroides: limite, velocidade da onda e divisão. Os
+/// asteroides em si são entidades do `World`.
 #[derive(Clone)]
 pub struct AsteroidCollection {
-    asteroids:      HashMap<Uuid, Asteroid>,
     pub max_asteroids:  usize,
+    // multiplicador de velocidade dos próximos spawns (definido pela onda)
+    pub speed_scale:    f32,
+    world_size:         f32,
 
-    spawn_counter:  u32,
-    spawn_cooldown: u32,
     rng: Option<StdRng>,
 }
 
+impl Default for AsteroidCollection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl AsteroidCollection {
     /// Jogo: sem se preocupar com RNG (entropia).
     pub fn new() -> Self {
-        Self::build(None)
+        Self::with_config(&GameConfig::default(), None)
     }
 
     /// Teste/benchmark: seed fixa, posições reproduzíveis.
     pub fn seeded(seed: u64) -> Self {
-        Self::build(Some(StdRng::seed_from_u64(seed)))
+        Self::with_config(&GameConfig::default(), Some(seed))
     }
 
-    fn build(rng: Option<StdRng>) -> Self {
-        let tick = TICK_RATE as f32;
-
+    pub fn with_config(config: &GameConfig, seed: Option<u64>) -> Self {
         Self {
-            asteroids: HashMap::new(),
-            max_asteroids: 32,
-
-            spawn_cooldown: (20.0 * tick) as u32,
-            spawn_counter: (1.0 * tick) as u32,
-            rng,
+            max_asteroids: config.max_asteroids,
+            speed_scale: 1.0,
+            world_size: config.world_size as f32,
+            rng: seed.map(StdRng::seed_from_u64),
         }
     }
 
-    pub fn spawn( &mut self, x: f32, y: f32, tier: AsteroidType) -> bool {
-        if tier == AsteroidType::BIG && self.asteroids.len() >= self.max_asteroids {
+    pub fn spawn(&mut self, world: &mut World, x: f32, y: f32, tier: AsteroidType, events: &mut Vec<GameEvent>) -> bool {
+        if tier == AsteroidType::BIG && world.count(EntityKind::Asteroid) >= self.max_asteroids {
             return false;
         }
 
-        let asteroid = match &mut self.rng {
-            Some(rng) => Asteroid::with_rng(x, y, tier, rng),
-            None => Asteroid::new(x, y, tier),
-        };
-        self.asteroids.insert(asteroid.id, asteroid);
+        let asteroid = self.build(x, y, tier);
+        Self::place(world, asteroid, None, events);
         true
     }
 
-    pub fn random_spawn(&mut self, ) -> bool {
-        let (x, y) = match &mut self.rng {
-            Some(rng) => (
-                rng.random_range(0.0..(WORLD_SIZE as f32)),
-                rng.random_range(0.0..(WORLD_SIZE as f32)),
-            ),
-            None => (
-                rand::random_range(0.0..(WORLD_SIZE as f32)),
-                rand::random_range(0.0..(WORLD_SIZE as f32)),
-            ),
-        };
-        self.spawn(x, y, AsteroidType::BIG)
+    fn build(&mut self, x: f32, y: f32, tier: AsteroidType) -> Asteroid {
+        match &mut self.rng {
+            Some(rng) => Asteroid::with_rng(x, y, tier, rng),
+            None => Asteroid::new(x, y, tier),
+        }.with_speed_scale(self.speed_scale)
     }
 
-    pub fn update(&mut self) {
-        for asteroid in self.asteroids.values_mut() {
-            asteroid.update();
-            asteroid.warp();
-        }
+    fn place(world: &mut World, asteroid: Asteroid, parent: Option<EntityId>, events: &mut Vec<GameEvent>) {
+        let (x, y, tier, shape) = (asteroid.x, asteroid.y, asteroid.size, asteroid.shape);
+        let id = asteroid.spawn(world);
+        events.push(GameEvent::AsteroidSpawned { id, tier, x, y, parent, shape });
+    }
 
-        if self.spawn_counter >= self.spawn_cooldown {
-            println!("Asteroid spawn");
-            self.random_spawn();
-            self.spawn_counter = 0;
+    fn roll(&mut self, range: Range<f32>) -> f32 {
+        match &mut self.rng {
+            Some(rng) => rng.random_range(range),
+            None => rand::random_range(range),
         }
+    }
 
-        self.spawn_counter += 1;
+    pub fn random_spawn(&mut self, world: &mut World, events: &mut Vec<GameEvent>) -> bool {
+        let x = self.roll(0.0..self.world_size);
+        let y = self.roll(0.0..self.world_size);
+        self.spawn(world, x, y, AsteroidType::BIG, events)
     }
 
-    pub fn remove_by_id(&mut self, id: Uuid) -> bool {
-        match self.asteroids.remove(&id) {
-            Some(asteroid) => {
-                self.split(asteroid);
-                true
-            }
-            None => false,
-        }
+    /// `by` é quem destruiu o asteroide (vai no evento, pra pontuação);
+    /// `impact` é o rumo do que bateu (ângulo da bala), se houver.
+    pub fn remove_by_id(
+        &mut self,
+        world: &mut World,
+        id: EntityId,
+        by: Option<ClientId>,
+        impact: Option<f32>,
+        events: &mut Vec<GameEvent>,
+    ) -> bool {
+        let (Some(tier), Some(transform)) = (world.tiers.get(id).copied(), world.transforms.get(id).copied()) else {
+            return false;
+        };
+        let velocity = world.velocities.get(id).copied().unwrap_or_default();
+        world.despawn(id);
+
+        events.push(GameEvent::AsteroidDestroyed { id, tier, by });
+        self.split(world, id, tier, (&transform, velocity), impact, events);
+        true
     }
 
-    /// Ao destruir um asteroide, gera os filhos do tier menor.
-    fn split(&mut self, asteroid: Asteroid) {
-        let child_size = match asteroid.size {
+    /// Ao destruir um asteroide, gera os filhos do tier menor. Eles herdam a
+    /// velocidade do pai e saem abertos pros dois lados do rumo do impacto
+    /// (sem impacto, rumo sorteado), um ao lado do outro em vez de empilhados.
+    fn split(
+        &mut self,
+        world: &mut World,
+        parent: EntityId,
+        tier: AsteroidType,
+        (at, velocity): (&Transform, Velocity),
+        impact: Option<f32>,
+        events: &mut Vec<GameEvent>,
+    ) {
+        let child_size = match tier {
             AsteroidType::BIG => Some(AsteroidType::MEDIUM),
             AsteroidType::MEDIUM => Some(AsteroidType::SMALL),
             AsteroidType::SMALL => None,
         };
+        let Some(child_size) = child_size else {
+            return;
+        };
 
-        if let Some(child_size) = child_size {
-            self.spawn(asteroid.x, asteroid.y, child_size);
-            self.spawn(asteroid.x, asteroid.y, child_size);
-        }
-    }
+        let heading = match impact {
+            Some(angle) => angle,
+            None => self.roll(0.0..TAU),
+        };
 
-    pub fn clear(&mut self) {
-        self.asteroids.clear();
+        for side in [-1.0, 1.0] {
+            let spread = self.roll(0.35..0.9);
+            let mut child = self.build(at.x, at.y, child_size).launched(heading + side * spread, velocity);
+
+            // um contorno inteiro pra cada lado: os irmãos não nascem se
+            // tocando. Perto da borda a posição fica crua; quem dá a volta
+            // ou prende é o `WrapSystem`, conforme o modo.
+            let offset = Velocity::from_angle(child.shape.radius() + 0.5, heading + side * FRAC_PI_2);
+            child.x += offset.vx;
+            child.y += offset.vy;
+            Self::place(world, child, Some(parent), events);
+        }
     }
 
-    pub fn get_hitboxes(&self) -> Vec<HitBox> {
-        self.asteroids.values().map(|a| a.hitbox()).collect()
-    }
+    /// Contorno de todos os asteroides vivos, pra quem abre sessão no meio
+    /// da partida (os outros receberam no `asteroid_spawned`).
+    pub fn shapes_json(world: &World) -> String {
+        let mut json = String::from("{\"type\":\"asteroid_shapes\",\"shapes\":[");
+        let mut comma = "";
 
-    pub fn len(&self) -> usize {
-        self.asteroids.len()
-    }
+        for id in world.ids(EntityKind::Asteroid) {
+            let Some(Shape::Polygon(polygon)) = world.colliders.get(id).map(|c| c.shape) else {
+                continue;
+            };
+            let points = serde_json::to_string(&polygon).unwrap_or_else(|_| "[]".to_string());
+            json.push_str(&format!("{}{{\"id\":{},\"points\":{}}}", comma, id, points));
+            comma = ",";
+        }
 
-    pub fn is_empty(&self) -> bool {
-        self.asteroids.is_empty()
+        json.push_str("]}");
+        json
     }
 
-    pub fn to_json(&self,) -> String {
+    pub fn to_json(world: &World) -> String {
         let mut json = String::from("\"Asteroids\":[");
         let mut comma = "";
 
-        for asteroid in self.asteroids.values() {
-            let asteroid_str = format!("{} {}", comma, &asteroid.to_json());
+        for id in world.ids(EntityKind::Asteroid) {
+            let (Some(transform), Some(collider)) = (world.transforms.get(id), world.colliders.get(id)) else {
+                continue;
+            };
+            let asteroid_str = format!(
+                "{} {{\"id\": {}, \"radius\": \"{}\", \"x\": {}, \"y\": {}, \"angle\": {} }}",
+                comma, id, collider.radius().round(), transform.x, transform.y, transform.angle
+            );
             json.push_str(&asteroid_str);
             comma = ",";
         }
 
         json += "]";
-        return json;
+        json
     }
 }
@@ -1,79 +1,80 @@
-use std::collections::HashMap;
-use uuid::Uuid;
+use crate::config::GameConfig;
 use crate::entities::bullet::Bullet;
-use crate::entities::hitbox::HitBox;
-use crate::entities::traits::collision_object::CollisionObject;
+use crate::entities::hitbox::EntityKind;
+use crate::entities::weapon::WeaponKind;
+use crate::types::EntityId;
+use crate::world::World;
 
+/// Limite padrão de balas vivas (config `max_bullets`).
 pub const MAX_BULLETS: usize = 2048;
 
+/// Regras das balas (limite de vivas). As balas em si são entidades do `World`.
 #[derive(Clone)]
 pub struct BulletCollection {
-    bullets: HashMap<Uuid, Bullet>,
     max_bullets: usize,
 }
 
+impl Default for BulletCollection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl BulletCollection {
     pub fn new() -> BulletCollection {
-        BulletCollection {
-            bullets: HashMap::with_capacity(MAX_BULLETS),
-            max_bullets: MAX_BULLETS,
-        }
+        Self::with_config(&GameConfig::default())
     }
 
-    pub fn get_bullets(&self, ) -> Vec<Bullet> {
-        self.bullets.values().cloned().collect()
+    pub fn with_config(config: &GameConfig) -> BulletCollection {
+        BulletCollection {
+            max_bullets: config.max_bullets,
+        }
     }
 
-    pub fn get_hitboxes(&self) -> Vec<HitBox> {
-        self.bullets.values().map(|b| b.hitbox()).collect()
+    /// Balas que já estão no ar ficam mesmo acima do novo limite.
+    pub fn set_max_bullets(&mut self, max_bullets: usize) {
+        self.max_bullets = max_bullets;
     }
 
-    pub fn add_bullet(&mut self, bullet: Bullet) -> bool {
-        if self.bullets.len() >= self.max_bullets {
-            return false;
+    pub fn add_bullet(&self, world: &mut World, bullet: Bullet) -> Option<EntityId> {
+        if world.count(EntityKind::Bullet) >= self.max_bullets {
+            return None;
         }
-        self.bullets.insert(bullet.id, bullet);
-        true
+        Some(bullet.spawn(world))
     }
 
-    pub fn add_bullets(&mut self, bullets: Vec<Bullet>) -> bool {
+    /// Devolve os ids das balas que entraram.
+    pub fn add_bullets(&self, world: &mut World, bullets: Vec<Bullet>) -> Vec<EntityId> {
         // só insere até o que cabe no limite
-        let free = self.max_bullets.saturating_sub(self.bullets.len());
+        let free = self.max_bullets.saturating_sub(world.count(EntityKind::Bullet));
 
-        for bullet in bullets.into_iter().take(free) {
-            self.bullets.insert(bullet.id, bullet);
-        }
-        true
+        bullets
+            .into_iter()
+            .take(free)
+            .map(|bullet| bullet.spawn(world))
+            .collect()
     }
 
-    pub fn rm_bullet(&mut self, bullet_id: Uuid) -> bool {
-        self.bullets.remove(&bullet_id).is_some()
-    }
-
-    /// Quem atirou essa bala (pra atribuir pontos na resolução do hit).
-    pub fn get_owner(&self, bullet_id: &Uuid) -> Option<Uuid> {
-        self.bullets.get(bullet_id).map(|b| b.player_id)
-    }
-
-    pub fn update(&mut self) {
-        self.bullets.retain(|_, bullet| {
-            bullet.update();
-            !bullet.is_destroyed()
-        });
-    }
-
-
-    pub fn to_json(&self) -> String {
+    pub fn to_json(world: &World) -> String {
         let mut json = String::from("\"Bullets\":[");
         let mut comma = "";
 
-        for bullet in self.bullets.values() {
-            let bullet_str = format!("{} {}", comma, &bullet.to_json());
+        for id in world.ids(EntityKind::Bullet) {
+            let Some(transform) = world.transforms.get(id) else {
+                continue;
+            };
+            // sem dono = tiro de disco voador
+            let owner = world.owners.get(id).map_or("null".to_string(), |o| format!("\"{}\"", o.player));
+            let weapon = world.projectiles.get(id).map_or(WeaponKind::Cannon, |p| p.weapon);
+            let bullet_str = format!(
+                "{} {{\"id\": {}, \"player_id\": {}, \"weapon\": \"{}\", \"x\": {}, \"y\": {}, \"angle\": {} }}",
+                comma, id, owner, weapon.name(), transform.x, transform.y, transform.angle
+            );
             json.push_str(&bullet_str);
             comma = ",";
         }
 
         json += "]";
-        return json;
+        json
     }
 }
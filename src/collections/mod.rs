@@ -1,3 +1,7 @@
 pub mod asteroid_collection;
 pub mod bullet_collection;
-pub mod player_collection;
\ No newline at end of file
+pub mod player_collection;
+pub mod powerup_collection;
+pub mod slot_map;
+pub mod team_collection;
+pub mod ufo_collection;
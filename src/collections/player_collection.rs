@@ -1,42 +1,73 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use uuid::Uuid;
 
 use crate::{entities::{
-    bullet::Bullet, player::{CMD, Player}
+    bullet::Bullet, player::{CMD, Damage, Player}, weapon::WeaponKind
 }, networking::router::MovePayload};
+use crate::events::GameEvent;
+use crate::config::GameConfig;
 use crate::entities::hitbox::HitBox;
+use crate::modes::battle_royale::SafeZone;
+use crate::world::components::Transform;
 use crate::entities::traits::collision_object::CollisionObject;
-use rand::SeedableRng;
+use rand::{Rng, SeedableRng};
 use rand::rngs::StdRng;
 
 #[derive(Clone)]
 pub struct PlayerCollection {
-    players: HashMap<Uuid, Player>,
+    players: BTreeMap<Uuid, Player>,
     max_players: usize,
+    // naves novas nascem com os valores dessa config
+    config: GameConfig,
+    // modo com volta: quem morre fica destruído e renasce (`ship.respawn_secs`)
+    respawn: bool,
     // None = jogo (entropia por entidade); Some = teste/benchmark (reproduzível)
     rng: Option<StdRng>,
 }
 
 
+impl Default for PlayerCollection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PlayerCollection {
     /// Jogo: sem se preocupar com RNG (entropia).
     pub fn new() -> PlayerCollection {
-        Self::build(None)
+        Self::with_config(&GameConfig::default(), None)
     }
 
     /// Teste/benchmark: seed fixa, spawn reproduzível.
     pub fn seeded(seed: u64) -> PlayerCollection {
-        Self::build(Some(StdRng::seed_from_u64(seed)))
+        Self::with_config(&GameConfig::default(), Some(seed))
     }
 
-    fn build(rng: Option<StdRng>) -> PlayerCollection {
+    pub fn with_config(config: &GameConfig, seed: Option<u64>) -> PlayerCollection {
         PlayerCollection {
-            players: HashMap::new(),
-            max_players: 255,
-            rng,
+            players: BTreeMap::new(),
+            max_players: config.max_players,
+            config: config.clone(),
+            respawn: false,
+            rng: seed.map(StdRng::seed_from_u64),
+        }
+    }
+
+    /// Config nova pra quem nascer daqui pra frente e pras naves vivas.
+    pub fn set_config(&mut self, config: &GameConfig) {
+        self.max_players = config.max_players;
+        self.config = config.clone();
+
+        for player in self.players.values_mut() {
+            player.apply_tuning(config);
         }
     }
 
+    /// Ligado pelo jogo conforme o modo; desligado, morte tira a nave.
+    pub fn set_respawn(&mut self, respawn: bool) {
+        self.respawn = respawn;
+    }
+
     pub fn is_full(&self) -> bool {
         self.players.len() >= self.max_players
     }
@@ -45,8 +76,31 @@ impl PlayerCollection {
         self.players.values().cloned().collect()
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = &Player> {
+        self.players.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.players.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    /// Na partida e não destruída.
+    pub fn is_alive(&self, id: &Uuid) -> bool {
+        self.players.get(id).is_some_and(|p| !p.is_destroyed())
+    }
+
+    /// Naves em jogo, sem contar quem espera a volta.
+    pub fn alive_count(&self) -> usize {
+        self.players.values().filter(|p| !p.is_destroyed()).count()
+    }
+
+    /// Só naves em jogo (destruída esperando a volta não colide).
     pub fn get_hitboxes(&self) -> Vec<HitBox> {
-        self.players.values().map(|p| p.hitbox()).collect()
+        self.players.values().filter(|p| !p.is_destroyed()).map(|p| p.hitbox()).collect()
     }
 
     pub fn get_player(&self, player_id: &Uuid) -> Option<Player> {
@@ -66,39 +120,148 @@ impl PlayerCollection {
             return Err("Player já existe");
         }
 
-        // println!("New player {}", client_id);
-
         let player = match &mut self.rng {
-            Some(rng) => Player::with_rng(client_id, rng),
-            None => Player::new(client_id),
+            Some(rng) => Player::with_rng(client_id, &self.config, rng),
+            None => Player::new(client_id, &self.config),
         };
         self.players.insert(*client_id, player);
 
         Ok(*client_id)
     }
 
+    /// Troca a arma da nave com os valores atuais de `weapons`.
+    pub fn choose_weapon(&mut self, client_id: &Uuid, kind: WeaponKind) -> bool {
+        match self.players.get_mut(client_id) {
+            Some(player) => {
+                player.equip(kind, &self.config);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Põe a nave parada em (x, y), apontando pra +x.
+    pub fn place(&mut self, client_id: &Uuid, x: f32, y: f32) -> bool {
+        match self.players.get_mut(client_id) {
+            Some(player) => {
+                *player.body_mut().0 = Transform::new(x, y, 0.0);
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn rm_player(&mut self, client_id: &Uuid) -> bool {
         self.players.remove(client_id).is_some()
     }
 
-    pub fn update(&mut self) -> Vec<Bullet> {
+    /// Aplica o dano da fonte; se a nave morreu, devolve o `PlayerKilled`
+    /// creditado a quem causou o último dano.
+    pub fn damage(&mut self, client_id: &Uuid, source: Damage) -> Option<GameEvent> {
+        let amount = source.amount(&self.config);
+        let player = self.players.get_mut(client_id)?;
+
+        if !player.take_damage(amount, source.by()) {
+            return None;
+        }
+        let killer = player.last_attacker();
+        self.kill(client_id);
+        Some(GameEvent::PlayerKilled { victim: *client_id, killer, cause: source.cause() })
+    }
+
+    /// Caminho da morte: destrói e agenda a volta ou, sem volta, tira do jogo.
+    pub fn kill(&mut self, client_id: &Uuid) -> bool {
+        let delay = self.config.ticks(self.config.ship.respawn_secs);
+        if !self.respawn || delay == 0 {
+            return self.rm_player(client_id);
+        }
+
+        match self.players.get_mut(client_id) {
+            Some(player) => {
+                player.destroy(delay);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Traz de volta, em posição sorteada, quem cumpriu o tempo destruído.
+    pub fn respawn_ready(&mut self) -> Vec<GameEvent> {
+        let world = self.config.world_size as f32;
+        let mut events = Vec::new();
+
+        for player in self.players.values_mut().filter(|p| p.ready_to_respawn()) {
+            let (x, y) = match &mut self.rng {
+                Some(rng) => (rng.random_range(0.0..world), rng.random_range(0.0..world)),
+                None => (rand::random_range(0.0..world), rand::random_range(0.0..world)),
+            };
+            player.respawn(x, y);
+            events.push(GameEvent::PlayerSpawned { id: player.get_id(), x, y });
+        }
+        events
+    }
+
+    pub fn add_score(&mut self, client_id: &Uuid, points: u32) {
+        if let Some(player) = self.players.get_mut(client_id) {
+            player.add_score(points);
+        }
+    }
+
+    pub fn reset_scores(&mut self) {
+        for player in self.players.values_mut() {
+            player.reset_score();
+        }
+    }
+
+    /// Jogador com maior pontuação (empate: o de menor id).
+    pub fn leader(&self) -> Option<&Player> {
+        self.players
+            .values()
+            .max_by(|a, b| a.score().cmp(&b.score()).then(b.get_id().cmp(&a.get_id())))
+    }
+
+    pub fn update(&mut self, wrap: bool) -> Vec<Bullet> {
         
         let mut bullets: Vec<Bullet> = Vec::new();
+        let world_size = self.config.world_size as f32;
 
         for player in self.players.values_mut() {
 
-            if let Some(bullet) = player.update() {
-                bullets.push(bullet);
-            }
+            bullets.extend(player.update(wrap, world_size));
         }
 
         bullets
     }
 
+    /// Como `update`, uma nave por tarefa. Os tiros voltam na ordem dos ids,
+    /// igual ao serial.
+    #[cfg(feature = "parallel")]
+    pub fn update_parallel(&mut self, wrap: bool) -> Vec<Bullet> {
+        use rayon::prelude::*;
+
+        let world_size = self.config.world_size as f32;
+        self.players
+            .par_iter_mut()
+            .flat_map_iter(|(_, player)| player.update(wrap, world_size))
+            .collect()
+    }
+
+    /// Segura na zona as naves que tentaram sair dela neste tick.
+    pub fn confine(&mut self, zone: &SafeZone) {
+        for player in self.players.values_mut() {
+            let (transform, velocity) = player.body_mut();
+            zone.confine(transform, velocity);
+        }
+    }
+
     // Fora do dominio de player_collection
     pub fn handle_command(&mut self, client_id: &Uuid, player_command: &MovePayload) { 
         if let Some(player) = self.players.get_mut(client_id) { 
 
+            if player_command.thrust || player_command.left || player_command.right || player_command.fire {
+                player.mark_active();
+            }
+
             if player_command.thrust    { player.push_command(CMD::UP);     } 
             if player_command.left      { player.push_command(CMD::LEFT);   } 
             if player_command.right     { player.push_command(CMD::RIGHT);  } 
@@ -0,0 +1,120 @@
+use crate::config::GameConfig;
+use crate::entities::hitbox::EntityKind;
+use crate::entities::powerup::{PowerUp, PowerUpKind};
+use crate::events::GameEvent;
+use crate::types::{ClientId, EntityId};
+use crate::world::World;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Regras dos power-ups: de quanto em quanto tempo nasce um, quantos cabem
+/// no mapa e quanto tempo ficam. Os itens em si são entidades do `World`.
+#[derive(Clone)]
+pub struct PowerUpCollection {
+    interval: u32,
+    max: usize,
+    lifetime: u32,
+    // ticks até o próximo item
+    countdown: u32,
+    world_size: f32,
+
+    rng: Option<StdRng>,
+}
+
+impl Default for PowerUpCollection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PowerUpCollection {
+    /// Jogo: sem se preocupar com RNG (entropia).
+    pub fn new() -> Self {
+        Self::with_config(&GameConfig::default(), None)
+    }
+
+    /// Teste/benchmark: seed fixa, itens reproduzíveis.
+    pub fn seeded(seed: u64) -> Self {
+        Self::with_config(&GameConfig::default(), Some(seed))
+    }
+
+    pub fn with_config(config: &GameConfig, seed: Option<u64>) -> Self {
+        let mut collection = Self {
+            interval: 0,
+            max: 0,
+            lifetime: 0,
+            countdown: 0,
+            world_size: config.world_size as f32,
+            rng: seed.map(StdRng::seed_from_u64),
+        };
+        collection.set_config(config);
+        collection
+    }
+
+    /// Valores novos da config; o relógio recomeça do intervalo novo.
+    pub fn set_config(&mut self, config: &GameConfig) {
+        let powerups = &config.powerups;
+        self.interval = config.ticks(powerups.interval_secs);
+        self.max = powerups.max;
+        self.lifetime = config.ticks(powerups.lifetime_secs).max(1);
+        self.countdown = self.interval;
+    }
+
+    /// Um tick do relógio; nasce um item quando ele zera e há vaga.
+    pub fn update(&mut self, world: &mut World, events: &mut Vec<GameEvent>) {
+        if self.interval == 0 {
+            return;
+        }
+
+        self.countdown = self.countdown.saturating_sub(1);
+        if self.countdown > 0 {
+            return;
+        }
+        self.countdown = self.interval;
+
+        if world.count(EntityKind::PowerUp) >= self.max {
+            return;
+        }
+
+        let powerup = match &mut self.rng {
+            Some(rng) => PowerUp::with_rng(self.world_size, self.lifetime, rng),
+            None => PowerUp::with_rng(self.world_size, self.lifetime, &mut rand::rng()),
+        };
+        self.spawn(world, powerup, events);
+    }
+
+    pub fn spawn(&mut self, world: &mut World, powerup: PowerUp, events: &mut Vec<GameEvent>) -> EntityId {
+        let (x, y, kind) = (powerup.x, powerup.y, powerup.kind);
+        let id = powerup.spawn(world);
+        events.push(GameEvent::PowerUpSpawned { id, kind, x, y });
+        id
+    }
+
+    /// Tira o item do mapa e devolve o efeito (None se já tinha saído).
+    pub fn collect(world: &mut World, id: EntityId, by: ClientId, events: &mut Vec<GameEvent>) -> Option<PowerUpKind> {
+        let kind = world.powerups.get(id).copied()?;
+        world.despawn(id);
+        events.push(GameEvent::PowerUpCollected { id, kind, by });
+        Some(kind)
+    }
+
+    pub fn to_json(world: &World) -> String {
+        let mut json = String::from("\"PowerUps\":[");
+        let mut comma = "";
+
+        for id in world.ids(EntityKind::PowerUp) {
+            let (Some(transform), Some(kind)) = (world.transforms.get(id), world.powerups.get(id)) else {
+                continue;
+            };
+            let powerup_str = format!(
+                "{} {{\"id\": {}, \"kind\": \"{}\", \"x\": {}, \"y\": {} }}",
+                comma, id, kind.name(), transform.x, transform.y
+            );
+            json.push_str(&powerup_str);
+            comma = ",";
+        }
+
+        json += "]";
+        json
+    }
+}
@@ -0,0 +1,126 @@
+use crate::types::EntityId;
+
+#[derive(Clone)]
+struct Slot<T> {
+    generation: u32,
+    value: Option<T>,
+}
+
+/// Armazenamento das entidades do mundo: vetor de slots com lista de
+/// livres. Inserir e remover é O(1) sem hash, e a iteração segue a ordem
+/// dos slots — a mesma em toda execução com as mesmas operações.
+#[derive(Clone)]
+pub struct SlotMap<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<u32>,
+    len: usize,
+}
+
+impl<T> Default for SlotMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SlotMap<T> {
+    pub fn new() -> SlotMap<T> {
+        SlotMap { slots: Vec::new(), free: Vec::new(), len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Entra com o id já conhecido (a entidade guarda o próprio id).
+    pub fn insert_with(&mut self, make: impl FnOnce(EntityId) -> T) -> EntityId {
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                let index = self.slots.len() as u32;
+                assert!(index <= EntityId::MAX_INDEX, "SlotMap cheio");
+                self.slots.push(Slot { generation: 0, value: None });
+                index
+            }
+        };
+
+        let slot = &mut self.slots[index as usize];
+        let id = EntityId::new(index, slot.generation);
+        slot.value = Some(make(id));
+        self.len += 1;
+        id
+    }
+
+    pub fn insert(&mut self, value: T) -> EntityId {
+        self.insert_with(|_| value)
+    }
+
+    pub fn contains(&self, id: EntityId) -> bool {
+        self.get(id).is_some()
+    }
+
+    pub fn get(&self, id: EntityId) -> Option<&T> {
+        self.slots
+            .get(id.index())
+            .filter(|slot| slot.generation == id.generation())
+            .and_then(|slot| slot.value.as_ref())
+    }
+
+    pub fn get_mut(&mut self, id: EntityId) -> Option<&mut T> {
+        self.slots
+            .get_mut(id.index())
+            .filter(|slot| slot.generation == id.generation())
+            .and_then(|slot| slot.value.as_mut())
+    }
+
+    pub fn remove(&mut self, id: EntityId) -> Option<T> {
+        let slot = self.slots.get_mut(id.index()).filter(|slot| slot.generation == id.generation())?;
+        let value = slot.value.take()?;
+        Self::release(slot, id.index() as u32, &mut self.free);
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Mantém só quem `keep` aprovar, na ordem dos slots.
+    pub fn retain(&mut self, mut keep: impl FnMut(EntityId, &mut T) -> bool) {
+        for (index, slot) in self.slots.iter_mut().enumerate() {
+            let id = EntityId::new(index as u32, slot.generation);
+            let Some(value) = slot.value.as_mut() else {
+                continue;
+            };
+
+            if !keep(id, value) {
+                slot.value = None;
+                Self::release(slot, index as u32, &mut self.free);
+                self.len -= 1;
+            }
+        }
+    }
+
+    /// Esvazia, mas invalida os ids antigos (não volta a geração).
+    pub fn clear(&mut self) {
+        self.retain(|_, _| false);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, &T)> {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| {
+            slot.value.as_ref().map(|value| (EntityId::new(index as u32, slot.generation), value))
+        })
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.slots.iter().filter_map(|slot| slot.value.as_ref())
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.slots.iter_mut().filter_map(|slot| slot.value.as_mut())
+    }
+
+    fn release(slot: &mut Slot<T>, index: u32, free: &mut Vec<u32>) {
+        slot.generation = (slot.generation + 1) & EntityId::MAX_GENERATION;
+        free.push(index);
+    }
+}
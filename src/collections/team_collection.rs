@@ -0,0 +1,96 @@
+use crate::collections::player_collection::PlayerCollection;
+use crate::types::TeamId;
+
+// Cores dos times, na ordem dos ids (time 1 = primeira cor).
+const TEAM_COLORS: [&str; 4] = ["#e74c3c", "#3498db", "#2ecc71", "#f1c40f"];
+
+pub const MAX_TEAMS: usize = TEAM_COLORS.len();
+
+#[derive(Clone)]
+pub struct Team {
+    pub id: TeamId,
+    pub color: &'static str,
+}
+
+/// Times da sala. Sem times (`none`) é todos contra todos.
+#[derive(Clone)]
+pub struct TeamCollection {
+    teams: Vec<Team>,
+    /// Se tiros e colisões entre aliados valem.
+    pub friendly_fire: bool,
+}
+
+impl Default for TeamCollection {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl TeamCollection {
+    /// Todos contra todos.
+    pub fn none() -> Self {
+        Self::new(0, true)
+    }
+
+    /// `count` times (no máximo `MAX_TEAMS`), ids a partir de 1.
+    pub fn new(count: usize, friendly_fire: bool) -> Self {
+        let teams = TEAM_COLORS
+            .iter()
+            .take(count)
+            .enumerate()
+            .map(|(i, color)| Team { id: i as TeamId + 1, color })
+            .collect();
+
+        Self { teams, friendly_fire }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.teams.is_empty()
+    }
+
+    pub fn contains(&self, team: TeamId) -> bool {
+        self.teams.iter().any(|t| t.id == team)
+    }
+
+    /// Time pedido, se existir; senão o time com menos jogadores.
+    pub fn assign(&self, players: &PlayerCollection, requested: Option<TeamId>) -> Option<TeamId> {
+        if let Some(team) = requested.filter(|t| self.contains(*t)) {
+            return Some(team);
+        }
+
+        self.teams
+            .iter()
+            .min_by_key(|t| (self.members(players, t.id), t.id))
+            .map(|t| t.id)
+    }
+
+    pub fn members(&self, players: &PlayerCollection, team: TeamId) -> usize {
+        players.iter().filter(|p| p.team() == Some(team)).count()
+    }
+
+    /// Soma da pontuação dos jogadores do time.
+    pub fn score(&self, players: &PlayerCollection, team: TeamId) -> u32 {
+        players
+            .iter()
+            .filter(|p| p.team() == Some(team))
+            .map(|p| p.score())
+            .sum()
+    }
+
+    pub fn to_json(&self, players: &PlayerCollection) -> String {
+        let mut json = String::from("\"Teams\":[");
+        let mut comma = "";
+
+        for team in self.teams.iter() {
+            let team_str = format!(
+                "{}{{\"id\": {}, \"color\": \"{}\", \"score\": {} }}",
+                comma, team.id, team.color, self.score(players, team.id)
+            );
+            json.push_str(&team_str);
+            comma = ",";
+        }
+
+        json.push(']');
+        json
+    }
+}
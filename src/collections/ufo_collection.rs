@@ -0,0 +1,231 @@
+use std::ops::Range;
+
+use crate::collections::player_collection::PlayerCollection;
+use crate::config::GameConfig;
+use crate::entities::bullet::Bullet;
+use crate::entities::hitbox::EntityKind;
+use crate::entities::traits::warp_object::WarpObject;
+use crate::entities::ufo::Ufo;
+use crate::events::GameEvent;
+use crate::types::{ClientId, EntityId};
+use crate::world::World;
+use crate::world::components::{Transform, Velocity};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+/// Regras e IA dos discos voadores: quando nascem, pra onde vagam e em
+/// quem atiram. Os discos em si são entidades do `World`; o tiro deles é
+/// uma bala sem dono (não pontua e não acerta outro disco).
+#[derive(Clone)]
+pub struct UfoCollection {
+    interval: u32,
+    max: usize,
+    // pixels por tick
+    speed: f32,
+    wander: u32,
+    fire_interval: u32,
+    range: f32,
+    inaccuracy: f32,
+    bullet_speed: f32,
+    bullet_ttl: u32,
+    damage: f32,
+    health: f32,
+    max_bullets: usize,
+    // ticks até o próximo disco
+    countdown: u32,
+    world_size: f32,
+
+    rng: Option<StdRng>,
+}
+
+impl Default for UfoCollection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UfoCollection {
+    /// Jogo: sem se preocupar com RNG (entropia).
+    pub fn new() -> Self {
+        Self::with_config(&GameConfig::default(), None)
+    }
+
+    /// Teste/benchmark: seed fixa, discos reproduzíveis.
+    pub fn seeded(seed: u64) -> Self {
+        Self::with_config(&GameConfig::default(), Some(seed))
+    }
+
+    pub fn with_config(config: &GameConfig, seed: Option<u64>) -> Self {
+        let mut collection = Self {
+            interval: 0,
+            max: 0,
+            speed: 0.0,
+            wander: 0,
+            fire_interval: 0,
+            range: 0.0,
+            inaccuracy: 0.0,
+            bullet_speed: 0.0,
+            bullet_ttl: 0,
+            damage: 0.0,
+            health: 0.0,
+            max_bullets: 0,
+            countdown: 0,
+            world_size: config.world_size as f32,
+            rng: seed.map(StdRng::seed_from_u64),
+        };
+        collection.set_config(config);
+        collection
+    }
+
+    /// Valores novos da config; o relógio recomeça do intervalo novo.
+    /// Discos que já estão no mapa seguem com a vida que tinham.
+    pub fn set_config(&mut self, config: &GameConfig) {
+        let ufos = &config.ufos;
+        self.interval = config.ticks(ufos.interval_secs);
+        self.max = ufos.max;
+        self.speed = config.per_tick(ufos.speed);
+        self.wander = config.ticks(ufos.wander_secs).max(1);
+        self.fire_interval = config.ticks(ufos.fire_interval_secs).max(1);
+        self.range = ufos.range;
+        self.inaccuracy = ufos.inaccuracy;
+        self.bullet_speed = config.per_tick(ufos.bullet_speed);
+        self.bullet_ttl = config.ticks(ufos.bullet_ttl_secs).max(1);
+        self.damage = ufos.damage;
+        self.health = ufos.health;
+        self.max_bullets = config.max_bullets;
+        self.countdown = self.interval;
+    }
+
+    fn roll(&mut self, range: Range<f32>) -> f32 {
+        if range.is_empty() {
+            return range.start;
+        }
+        match &mut self.rng {
+            Some(rng) => rng.random_range(range),
+            None => rand::random_range(range),
+        }
+    }
+
+    /// Um tick: nasce disco quando o relógio zera e há vaga; cada disco
+    /// troca de rumo e atira quando chega a vez.
+    pub fn update(&mut self, world: &mut World, players: &PlayerCollection, events: &mut Vec<GameEvent>) {
+        if self.interval > 0 {
+            self.countdown = self.countdown.saturating_sub(1);
+            if self.countdown == 0 {
+                self.countdown = self.interval;
+                if world.count(EntityKind::Ufo) < self.max {
+                    let x = self.roll(0.0..self.world_size);
+                    let y = self.roll(0.0..self.world_size);
+                    self.spawn(world, x, y, events);
+                }
+            }
+        }
+
+        let ids: Vec<EntityId> = world.ids(EntityKind::Ufo).collect();
+        for id in ids {
+            self.think(world, players, id, events);
+        }
+    }
+
+    /// Disco novo em (x, y), com rumo sorteado.
+    pub fn spawn(&mut self, world: &mut World, x: f32, y: f32, events: &mut Vec<GameEvent>) -> EntityId {
+        let heading = self.roll(0.0..std::f32::consts::TAU);
+        let ufo = Ufo { health: self.health, wander_in: self.wander, fire_in: self.fire_interval };
+        let id = ufo.spawn(world, x, y, Velocity::from_angle(self.speed, heading));
+        events.push(GameEvent::UfoSpawned { id, x, y });
+        id
+    }
+
+    fn think(&mut self, world: &mut World, players: &PlayerCollection, id: EntityId, events: &mut Vec<GameEvent>) {
+        let (Some(mut ufo), Some(&transform)) = (world.ufos.get(id).copied(), world.transforms.get(id)) else {
+            return;
+        };
+
+        ufo.wander_in = ufo.wander_in.saturating_sub(1);
+        if ufo.wander_in == 0 {
+            ufo.wander_in = self.wander;
+            let heading = self.roll(0.0..std::f32::consts::TAU);
+            world.velocities.insert(id, Velocity::from_angle(self.speed, heading));
+        }
+
+        ufo.fire_in = ufo.fire_in.saturating_sub(1);
+        if ufo.fire_in == 0 {
+            ufo.fire_in = self.fire_interval;
+            if let Some(aim) = self.aim(players, &transform) {
+                let error = self.roll(-self.inaccuracy..self.inaccuracy);
+                self.shoot(world, &transform, aim + error, events);
+            }
+        }
+
+        world.ufos.insert(id, ufo);
+    }
+
+    /// Ângulo até a nave mais perto dentro do alcance, contando a volta do mundo.
+    fn aim(&self, players: &PlayerCollection, from: &Transform) -> Option<f32> {
+        let half = self.world_size / 2.0;
+        let wrapped = |delta: f32| (delta + half).rem_euclid(self.world_size) - half;
+
+        players
+            .iter()
+            .filter(|p| !p.is_destroyed())
+            .map(|p| {
+                let (x, y) = p.position();
+                (wrapped(x - from.x), wrapped(y - from.y))
+            })
+            .filter(|(dx, dy)| dx * dx + dy * dy <= self.range * self.range)
+            .min_by(|a, b| (a.0 * a.0 + a.1 * a.1).total_cmp(&(b.0 * b.0 + b.1 * b.1)))
+            .map(|(dx, dy)| dy.atan2(dx))
+    }
+
+    fn shoot(&self, world: &mut World, from: &Transform, angle: f32, events: &mut Vec<GameEvent>) {
+        if world.count(EntityKind::Bullet) >= self.max_bullets {
+            return;
+        }
+
+        let id = Bullet::hostile(from.x, from.y, self.bullet_speed, angle, self.bullet_ttl, self.damage).spawn(world);
+        events.push(GameEvent::BulletFired { id, owner: None });
+    }
+
+    /// Tira vida do disco; derrubado, sai do mapa com o `UfoDestroyed`.
+    /// True se caiu agora.
+    pub fn damage(world: &mut World, id: EntityId, amount: f32, by: Option<ClientId>, events: &mut Vec<GameEvent>) -> bool {
+        let Some(ufo) = world.ufos.get_mut(id) else {
+            return false;
+        };
+
+        ufo.health -= amount;
+        if ufo.health > 0.0 {
+            return false;
+        }
+        Self::destroy(world, id, by, events)
+    }
+
+    pub fn destroy(world: &mut World, id: EntityId, by: Option<ClientId>, events: &mut Vec<GameEvent>) -> bool {
+        if !world.ufos.contains(id) {
+            return false;
+        }
+        world.despawn(id);
+        events.push(GameEvent::UfoDestroyed { id, by });
+        true
+    }
+
+    pub fn to_json(world: &World) -> String {
+        let mut json = String::from("\"Ufos\":[");
+        let mut comma = "";
+
+        for id in world.ids(EntityKind::Ufo) {
+            let (Some(transform), Some(ufo)) = (world.transforms.get(id), world.ufos.get(id)) else {
+                continue;
+            };
+            let ufo_str = format!(
+                "{} {{\"id\": {}, \"x\": {}, \"y\": {}, \"health\": {} }}",
+                comma, id, transform.x, transform.y, ufo.health
+            );
+            json.push_str(&ufo_str);
+            comma = ",";
+        }
+
+        json += "]";
+        json
+    }
+}
@@ -0,0 +1,844 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tracing_subscriber::EnvFilter;
+
+use crate::collections::bullet_collection::MAX_BULLETS;
+use crate::collections::team_collection::MAX_TEAMS;
+use crate::entities::asteroid::AsteroidType;
+use crate::entities::weapon::WeaponKind;
+use crate::modes;
+use crate::systems::waves::DifficultyCurve;
+
+/// Arquivo lido quando nem `--config` nem `ASTEROIDS_CONFIG` apontam outro.
+pub const DEFAULT_CONFIG_PATH: &str = "asteroids.toml";
+
+/// Prefixo das variáveis de ambiente: `ASTEROIDS_SHIP__ACCELERATION=8`
+/// sobrescreve `ship.acceleration` (`__` separa as seções).
+pub const ENV_PREFIX: &str = "ASTEROIDS_";
+
+/// Chaves que a sala usa ao ser montada e só mudam reiniciando o servidor.
+/// Um prefixo de seção (`mode`) vale pra todas as chaves dela.
+pub const RESTART_ONLY: &[&str] = &["port", "tick_rate", "world_size", "teams", "mode", "admin", "proxy", "log", "replay"];
+
+/// Configuração do servidor e da partida. Valores de tempo em segundos e
+/// taxas "por segundo"; cada entidade converte pra ticks ao nascer.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GameConfig {
+    pub port: u16,
+    pub tick_rate: u8,
+    pub world_size: u32,
+    pub max_players: usize,
+    pub max_asteroids: usize,
+    pub max_bullets: usize,
+    /// 0 = todos contra todos.
+    pub teams: usize,
+    pub friendly_fire: bool,
+    pub mode: ModeConfig,
+    pub ship: ShipConfig,
+    pub weapons: WeaponsConfig,
+    pub damage: DamageConfig,
+    pub physics: PhysicsConfig,
+    pub powerups: PowerUpConfig,
+    pub ufos: UfoConfig,
+    pub waves: DifficultyCurve,
+    pub admin: AdminConfig,
+    pub proxy: ProxyConfig,
+    pub log: LogConfig,
+    pub shutdown: ShutdownConfig,
+    pub afk: AfkConfig,
+    pub heartbeat: HeartbeatConfig,
+    pub replay: ReplayConfig,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ModeConfig {
+    /// `deathmatch`, `survival`, `time_attack` ou `battle_royale`.
+    pub name: String,
+    pub score_limit: u32,
+    pub time_limit_secs: u32,
+    pub zone_shrink_secs: f32,
+    pub zone_grace_secs: f32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ShipConfig {
+    pub acceleration: f32,
+    pub deceleration: f32,
+    /// Radianos por segundo.
+    pub turn_speed: f32,
+    /// Pixels por tick (teto da magnitude da velocidade).
+    pub max_speed: f32,
+    pub health: f32,
+    /// Escudo cheio; gasta antes da vida. 0 = sem escudo.
+    pub shield: f32,
+    /// Pontos de escudo por segundo, depois de `shield_delay_secs` sem dano.
+    pub shield_regen: f32,
+    pub shield_delay_secs: f32,
+    /// Tempo até a nave destruída voltar, nos modos que têm volta.
+    /// 0 = quem morre sai do jogo.
+    pub respawn_secs: f32,
+}
+
+/// Dano por fonte fora as armas (esse fica em `weapons`). Com os padrões
+/// tudo mata na hora (vida 100, sem escudo).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DamageConfig {
+    pub asteroid_small: f32,
+    pub asteroid_medium: f32,
+    pub asteroid_big: f32,
+    /// Trombada com a física ligada, por px/tick de aproximação.
+    pub collision: f32,
+}
+
+/// Armas das naves. Além da recarga entre tiros, `limit` escolhe o que
+/// segura o gatilho: calor, munição ou nada.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WeaponsConfig {
+    /// Arma com que a nave nasce (o jogador troca com `choose_weapon`).
+    pub default: WeaponKind,
+    pub limit: WeaponLimit,
+    /// Calor perdido por segundo; a 100 a arma trava até esfriar de todo.
+    pub cooling: f32,
+    /// Tempo pra encher o pente depois de esvaziar.
+    pub reload_secs: f32,
+    pub cannon: WeaponConfig,
+    /// Raio instantâneo; o alcance é `speed * ttl_secs`.
+    pub laser: WeaponConfig,
+    /// `speed` 0 deixa a mina parada onde a nave estava.
+    pub mine: WeaponConfig,
+    pub missile: WeaponConfig,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WeaponLimit {
+    /// Só a recarga entre tiros.
+    None,
+    /// Cada tiro esquenta (`heat`); a arma esfria sozinha.
+    Heat,
+    /// Pente de `ammo` tiros, recarregado quando acaba.
+    Ammo,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WeaponConfig {
+    /// Pixels por segundo; canhão e míssil somam a velocidade da nave.
+    pub speed: f32,
+    pub ttl_secs: f32,
+    pub damage: f32,
+    pub cooldown_secs: f32,
+    /// Projéteis por disparo, abertos num leque de `spread` radianos.
+    pub projectiles: u32,
+    pub spread: f32,
+    /// Empurrão pra trás na nave a cada disparo, em px/s.
+    pub knockback: f32,
+    /// Calor por disparo (trava em 100), com `limit = "heat"`.
+    pub heat: f32,
+    /// Tiros por pente, com `limit = "ammo"`.
+    pub ammo: u32,
+    /// Radianos por segundo virando atrás do alvo; 0 = reto.
+    pub turn_rate: f32,
+}
+
+/// Regras opcionais de física: asteroides quicam entre si e a nave, em vez
+/// de morrer no toque, leva dano pela força da trombada (`damage.collision`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PhysicsConfig {
+    pub enabled: bool,
+    /// 1 = choque elástico; 0 = ninguém quica na normal do contato.
+    pub restitution: f32,
+    /// Massa da nave; a dos asteroides sai do tier (o pequeno pesa 1).
+    pub ship_mass: f32,
+}
+
+/// Itens que aparecem no mapa de tempos em tempos e dão um efeito
+/// temporário a quem encosta.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PowerUpConfig {
+    /// Segundos entre um item e outro. 0 desliga.
+    pub interval_secs: f32,
+    /// Itens no mapa ao mesmo tempo.
+    pub max: usize,
+    /// Tempo que o item espera alguém antes de sumir.
+    pub lifetime_secs: f32,
+    /// Duração do efeito em quem pegou.
+    pub duration_secs: f32,
+    /// Multiplica a recarga do tiro (`rapid_fire`).
+    pub rapid_fire_cooldown: f32,
+    /// Radianos entre as balas do leque (`spread_shot`).
+    pub spread_angle: f32,
+    /// Escudo extra, que some no fim do efeito (`shield`).
+    pub shield: f32,
+    /// Multiplica `ship.max_speed` (`speed_boost`).
+    pub speed_boost: f32,
+}
+
+/// Discos voadores inimigos: aparecem de tempos em tempos, vagam pelo mapa
+/// e atiram na nave mais perto.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UfoConfig {
+    /// Segundos entre um disco e outro. 0 desliga.
+    pub interval_secs: f32,
+    /// Discos no mapa ao mesmo tempo.
+    pub max: usize,
+    /// Pixels por segundo.
+    pub speed: f32,
+    /// De quanto em quanto tempo sorteia um rumo novo.
+    pub wander_secs: f32,
+    pub fire_interval_secs: f32,
+    /// Só mira em nave até essa distância.
+    pub range: f32,
+    /// Erro máximo da mira, em radianos pra cada lado.
+    pub inaccuracy: f32,
+    /// Pixels por segundo.
+    pub bullet_speed: f32,
+    pub bullet_ttl_secs: f32,
+    /// Dano do tiro e da trombada com o disco.
+    pub damage: f32,
+    pub health: f32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AdminConfig {
+    /// Token do canal de admin (`Authorization: Bearer ...`). Vazio = desligado.
+    pub secret: String,
+    /// Arquivo onde cada ação de admin é anotada. Vazio = só no stdout.
+    pub audit_log: String,
+}
+
+/// Proxy reverso na frente do servidor (o da hospedagem, por exemplo).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProxyConfig {
+    /// Quantos proxies confiáveis anotam no `X-Forwarded-For`. 0 = o
+    /// cabeçalho é ignorado e vale o endereço da conexão (ban e auditoria).
+    pub trusted_hops: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogConfig {
+    /// Filtro no formato do `tracing` (`info`, `debug`, `asteroids_server=debug,tower_http=warn`).
+    pub level: String,
+    pub format: LogFormat,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    Pretty,
+    Json,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ShutdownConfig {
+    /// Tempo máximo esperando os clientes receberem o aviso e fecharem.
+    pub grace_secs: f32,
+    /// Endereço (host[:porta]) sugerido aos clientes pra reconectar. Vazio = nenhum.
+    pub reconnect: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AfkConfig {
+    /// Segundos sem comando de verdade até tirar a nave. 0 desliga.
+    pub idle_secs: f32,
+    /// Aviso (`idle_warning`) sai esse tanto antes de `idle_secs`.
+    pub warn_secs: f32,
+    pub action: AfkAction,
+}
+
+/// O que acontece com quem ficou parado.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AfkAction {
+    /// Sai do jogo mas a sessão continua (vê a partida, pode voltar).
+    Spectate,
+    /// Fecha o socket.
+    Disconnect,
+}
+
+/// Ping do WebSocket pra achar conexões meio abertas (TCP que sumiu sem erro).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HeartbeatConfig {
+    pub interval_secs: f32,
+    /// Sem nada recebido (nem pong) por esse tempo, o socket é fechado.
+    pub timeout_secs: f32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReplayConfig {
+    /// Arquivo onde a partida é gravada (`cargo run --bin replay -- <arquivo>`
+    /// re-simula). Vazio = não grava.
+    pub path: String,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            port: 8080,
+            tick_rate: 32,
+            world_size: 6000,
+            max_players: 255,
+            max_asteroids: 32,
+            max_bullets: MAX_BULLETS,
+            teams: 0,
+            friendly_fire: true,
+            mode: ModeConfig::default(),
+            ship: ShipConfig::default(),
+            weapons: WeaponsConfig::default(),
+            damage: DamageConfig::default(),
+            physics: PhysicsConfig::default(),
+            powerups: PowerUpConfig::default(),
+            ufos: UfoConfig::default(),
+            waves: DifficultyCurve::default(),
+            admin: AdminConfig::default(),
+            proxy: ProxyConfig::default(),
+            log: LogConfig::default(),
+            shutdown: ShutdownConfig::default(),
+            afk: AfkConfig::default(),
+            heartbeat: HeartbeatConfig::default(),
+            replay: ReplayConfig::default(),
+        }
+    }
+}
+
+impl Default for ModeConfig {
+    fn default() -> Self {
+        Self {
+            name: "deathmatch".into(),
+            score_limit: 10_000,
+            time_limit_secs: 180,
+            zone_shrink_secs: 30.0,
+            zone_grace_secs: 5.0,
+        }
+    }
+}
+
+impl Default for ShipConfig {
+    fn default() -> Self {
+        Self {
+            acceleration: 7.0,
+            deceleration: 2.0,
+            turn_speed: 2.0,
+            max_speed: 10.0,
+            health: 100.0,
+            shield: 0.0,
+            shield_regen: 10.0,
+            shield_delay_secs: 3.0,
+            respawn_secs: 3.0,
+        }
+    }
+}
+
+impl Default for DamageConfig {
+    fn default() -> Self {
+        Self {
+            asteroid_small: 100.0,
+            asteroid_medium: 100.0,
+            asteroid_big: 100.0,
+            collision: 8.0,
+        }
+    }
+}
+
+impl DamageConfig {
+    pub fn asteroid(&self, tier: AsteroidType) -> f32 {
+        match tier {
+            AsteroidType::SMALL => self.asteroid_small,
+            AsteroidType::MEDIUM => self.asteroid_medium,
+            AsteroidType::BIG => self.asteroid_big,
+        }
+    }
+}
+
+impl Default for PhysicsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            restitution: 1.0,
+            ship_mass: 1.5,
+        }
+    }
+}
+
+impl Default for PowerUpConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 15.0,
+            max: 4,
+            lifetime_secs: 20.0,
+            duration_secs: 10.0,
+            rapid_fire_cooldown: 0.4,
+            spread_angle: 0.2,
+            shield: 50.0,
+            speed_boost: 1.5,
+        }
+    }
+}
+
+impl Default for UfoConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 30.0,
+            max: 2,
+            speed: 120.0,
+            wander_secs: 2.0,
+            fire_interval_secs: 1.5,
+            range: 900.0,
+            inaccuracy: 0.25,
+            bullet_speed: 300.0,
+            bullet_ttl_secs: 3.0,
+            damage: 100.0,
+            health: 100.0,
+        }
+    }
+}
+
+impl Default for AdminConfig {
+    fn default() -> Self {
+        Self { secret: String::new(), audit_log: "admin_audit.log".into() }
+    }
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self { level: "info".into(), format: LogFormat::Pretty }
+    }
+}
+
+impl Default for ShutdownConfig {
+    fn default() -> Self {
+        Self { grace_secs: 5.0, reconnect: String::new() }
+    }
+}
+
+impl Default for AfkConfig {
+    fn default() -> Self {
+        Self { idle_secs: 90.0, warn_secs: 15.0, action: AfkAction::Spectate }
+    }
+}
+
+impl Default for HeartbeatConfig {
+    fn default() -> Self {
+        Self { interval_secs: 10.0, timeout_secs: 30.0 }
+    }
+}
+
+impl Default for WeaponsConfig {
+    fn default() -> Self {
+        Self {
+            default: WeaponKind::Cannon,
+            limit: WeaponLimit::None,
+            cooling: 30.0,
+            reload_secs: 2.0,
+            cannon: WeaponConfig::cannon(),
+            laser: WeaponConfig {
+                speed: 1500.0,
+                ttl_secs: 0.4,
+                damage: 40.0,
+                cooldown_secs: 0.25,
+                heat: 20.0,
+                ammo: 8,
+                ..WeaponConfig::cannon()
+            },
+            mine: WeaponConfig {
+                speed: 0.0,
+                ttl_secs: 20.0,
+                cooldown_secs: 1.0,
+                heat: 25.0,
+                ammo: 3,
+                ..WeaponConfig::cannon()
+            },
+            missile: WeaponConfig {
+                speed: 300.0,
+                ttl_secs: 4.0,
+                cooldown_secs: 1.2,
+                knockback: 60.0,
+                heat: 35.0,
+                ammo: 4,
+                turn_rate: 2.5,
+                ..WeaponConfig::cannon()
+            },
+        }
+    }
+}
+
+impl WeaponsConfig {
+    pub fn get(&self, kind: WeaponKind) -> &WeaponConfig {
+        match kind {
+            WeaponKind::Cannon => &self.cannon,
+            WeaponKind::Laser => &self.laser,
+            WeaponKind::Mine => &self.mine,
+            WeaponKind::Missile => &self.missile,
+        }
+    }
+}
+
+impl Default for WeaponConfig {
+    fn default() -> Self {
+        Self::cannon()
+    }
+}
+
+impl WeaponConfig {
+    /// Tiro padrão: o mesmo de antes das armas.
+    pub fn cannon() -> Self {
+        Self {
+            speed: 450.0,
+            ttl_secs: 5.0,
+            damage: 100.0,
+            cooldown_secs: 0.4,
+            projectiles: 1,
+            spread: 0.0,
+            knockback: 0.0,
+            heat: 10.0,
+            ammo: 12,
+            turn_rate: 0.0,
+        }
+    }
+}
+
+impl GameConfig {
+    /// Ordem de precedência: padrão < arquivo < ambiente < linha de comando.
+    /// O arquivo vem de `--config`, `ASTEROIDS_CONFIG` ou `asteroids.toml`
+    /// (esse último só se existir).
+    pub fn load() -> Result<GameConfig, String> {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        let mut cli = parse_args(&args)?;
+
+        let config = match config_path(&mut cli) {
+            Some(path) => Self::from_file(&path)?,
+            None => Self::default(),
+        };
+
+        // o ambiente tem de tudo: variável com o prefixo que não é opção só avisa
+        let (env, ignored) = config.known_overrides(env_overrides());
+        for key in ignored {
+            eprintln!("Aviso: variável de ambiente ignorada, opção desconhecida: {}", key);
+        }
+
+        let config = config.with_overrides(&env)?.with_overrides(&cli)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Arquivo que `load` leria agora (o que o servidor observa pra recarregar).
+    pub fn source_path() -> Option<String> {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        config_path(&mut parse_args(&args).ok()?)
+    }
+
+    pub fn from_file(path: &str) -> Result<GameConfig, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+        Self::from_toml(&text).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    pub fn from_toml(text: &str) -> Result<GameConfig, String> {
+        toml::from_str(text).map_err(|e| e.to_string())
+    }
+
+    /// Aplica pares `chave.com.pontos = valor`. O valor é lido como TOML
+    /// (`8`, `true`, `0.5`) e, se não for, como texto.
+    pub fn with_overrides(self, overrides: &[(String, String)]) -> Result<GameConfig, String> {
+        if overrides.is_empty() {
+            return Ok(self);
+        }
+
+        let mut root = toml::Value::try_from(&self).map_err(|e| e.to_string())?;
+
+        for (key, raw) in overrides {
+            let slot = key
+                .split('.')
+                .try_fold(&mut root, |node, part| node.get_mut(part))
+                .ok_or_else(|| format!("opção desconhecida: {}", key))?;
+
+            *slot = parse_value(raw);
+        }
+
+        root.try_into().map_err(|e: toml::de::Error| e.to_string())
+    }
+
+    /// Separa os pares cuja chave existe na config dos que não existem
+    /// (devolvidos só pelo nome).
+    pub fn known_overrides(&self, overrides: Vec<(String, String)>) -> (Vec<(String, String)>, Vec<String>) {
+        let keys = self.flatten();
+        let (known, unknown): (Vec<_>, Vec<_>) = overrides.into_iter().partition(|(key, _)| keys.contains_key(key));
+        (known, unknown.into_iter().map(|(key, _)| key).collect())
+    }
+
+    /// Junta todos os problemas numa mensagem só, pra corrigir de uma vez.
+    pub fn validate(&self) -> Result<(), String> {
+        let mut errors = Vec::new();
+
+        if self.tick_rate == 0 || self.tick_rate > 128 {
+            errors.push(format!("tick_rate deve estar entre 1 e 128 (recebido {})", self.tick_rate));
+        }
+        if self.world_size < 500 {
+            errors.push(format!("world_size deve ser pelo menos 500 (recebido {})", self.world_size));
+        }
+        if self.max_players == 0 {
+            errors.push("max_players deve ser maior que 0".to_string());
+        }
+        if self.max_bullets == 0 {
+            errors.push("max_bullets deve ser maior que 0".to_string());
+        }
+        // zero encerraria a rodada em todo tick
+        if self.mode.score_limit == 0 {
+            errors.push("mode.score_limit deve ser maior que 0".to_string());
+        }
+        if self.mode.time_limit_secs == 0 {
+            errors.push("mode.time_limit_secs deve ser maior que 0".to_string());
+        }
+        if self.teams > MAX_TEAMS {
+            errors.push(format!("teams deve ser no máximo {} (recebido {})", MAX_TEAMS, self.teams));
+        }
+        if let Err(e) = EnvFilter::try_new(&self.log.level) {
+            errors.push(format!("log.level inválido ({}): {}", self.log.level, e));
+        }
+        if modes::from_config(self).is_none() {
+            errors.push(format!("modo desconhecido: {}", self.mode.name));
+        }
+
+        // NaN passa por qualquer comparação e infinito estoura as durações:
+        // todo f32 da config entra numa das listas
+        let positive = [
+            ("mode.zone_shrink_secs", self.mode.zone_shrink_secs),
+            ("ship.acceleration", self.ship.acceleration),
+            ("ship.turn_speed", self.ship.turn_speed),
+            ("ship.max_speed", self.ship.max_speed),
+            ("ship.health", self.ship.health),
+            ("physics.ship_mass", self.physics.ship_mass),
+            ("powerups.lifetime_secs", self.powerups.lifetime_secs),
+            ("powerups.rapid_fire_cooldown", self.powerups.rapid_fire_cooldown),
+            ("powerups.speed_boost", self.powerups.speed_boost),
+            ("ufos.wander_secs", self.ufos.wander_secs),
+            ("ufos.fire_interval_secs", self.ufos.fire_interval_secs),
+            ("ufos.bullet_ttl_secs", self.ufos.bullet_ttl_secs),
+            ("ufos.health", self.ufos.health),
+            ("heartbeat.interval_secs", self.heartbeat.interval_secs),
+            ("heartbeat.timeout_secs", self.heartbeat.timeout_secs),
+        ];
+        for (name, value) in positive {
+            if !value.is_finite() || value <= 0.0 {
+                errors.push(format!("{} deve ser maior que 0 (recebido {})", name, value));
+            }
+        }
+
+        let non_negative = [
+            ("mode.zone_grace_secs", self.mode.zone_grace_secs),
+            ("waves.asteroids_per_player", self.waves.asteroids_per_player),
+            ("waves.speed_per_wave", self.waves.speed_per_wave),
+            ("waves.max_speed_scale", self.waves.max_speed_scale),
+            ("waves.intermission_secs", self.waves.intermission_secs),
+            ("ship.deceleration", self.ship.deceleration),
+            ("ship.shield", self.ship.shield),
+            ("ship.shield_regen", self.ship.shield_regen),
+            ("ship.shield_delay_secs", self.ship.shield_delay_secs),
+            ("ship.respawn_secs", self.ship.respawn_secs),
+            ("damage.asteroid_small", self.damage.asteroid_small),
+            ("damage.asteroid_medium", self.damage.asteroid_medium),
+            ("damage.asteroid_big", self.damage.asteroid_big),
+            ("damage.collision", self.damage.collision),
+            ("powerups.interval_secs", self.powerups.interval_secs),
+            ("powerups.duration_secs", self.powerups.duration_secs),
+            ("powerups.spread_angle", self.powerups.spread_angle),
+            ("powerups.shield", self.powerups.shield),
+            ("weapons.cooling", self.weapons.cooling),
+            ("ufos.interval_secs", self.ufos.interval_secs),
+            ("ufos.speed", self.ufos.speed),
+            ("ufos.range", self.ufos.range),
+            ("ufos.inaccuracy", self.ufos.inaccuracy),
+            ("ufos.bullet_speed", self.ufos.bullet_speed),
+            ("ufos.damage", self.ufos.damage),
+            ("weapons.reload_secs", self.weapons.reload_secs),
+            ("shutdown.grace_secs", self.shutdown.grace_secs),
+            ("afk.idle_secs", self.afk.idle_secs),
+            ("afk.warn_secs", self.afk.warn_secs),
+        ];
+        for (name, value) in non_negative {
+            if !value.is_finite() || value < 0.0 {
+                errors.push(format!("{} não pode ser negativo (recebido {})", name, value));
+            }
+        }
+
+        for kind in WeaponKind::ALL {
+            let weapon = self.weapons.get(kind);
+            let name = kind.name();
+            if !weapon.ttl_secs.is_finite() || weapon.ttl_secs <= 0.0 {
+                errors.push(format!("weapons.{}.ttl_secs deve ser maior que 0 (recebido {})", name, weapon.ttl_secs));
+            }
+            if weapon.projectiles == 0 || weapon.ammo == 0 {
+                errors.push(format!("weapons.{}.projectiles e weapons.{}.ammo devem ser maiores que 0", name, name));
+            }
+            let non_negative = [
+                ("speed", weapon.speed),
+                ("damage", weapon.damage),
+                ("cooldown_secs", weapon.cooldown_secs),
+                ("spread", weapon.spread),
+                ("knockback", weapon.knockback),
+                ("heat", weapon.heat),
+                ("turn_rate", weapon.turn_rate),
+            ];
+            for (field, value) in non_negative {
+                if !value.is_finite() || value < 0.0 {
+                    errors.push(format!("weapons.{}.{} não pode ser negativo (recebido {})", name, field, value));
+                }
+            }
+        }
+
+        if !(0.0..=1.0).contains(&self.physics.restitution) {
+            errors.push(format!("physics.restitution deve estar entre 0 e 1 (recebido {})", self.physics.restitution));
+        }
+        if self.afk.idle_secs > 0.0 && self.afk.warn_secs > self.afk.idle_secs {
+            errors.push("afk.warn_secs não pode passar de afk.idle_secs".to_string());
+        }
+        if self.heartbeat.timeout_secs < self.heartbeat.interval_secs {
+            errors.push("heartbeat.timeout_secs deve ser pelo menos heartbeat.interval_secs".to_string());
+        }
+
+        if errors.is_empty() {
+            return Ok(());
+        }
+        Err(errors.join("\n"))
+    }
+
+    /// Chaves (`ship.acceleration`) cujo valor difere entre as duas configs.
+    pub fn diff(&self, other: &GameConfig) -> Vec<String> {
+        let (before, after) = (self.flatten(), other.flatten());
+        after
+            .iter()
+            .filter(|(key, value)| before.get(*key) != Some(value))
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    /// Confere se `new` pode entrar com o jogo rodando e devolve o que muda.
+    /// Recusa tudo de `RESTART_ONLY`, dizendo valor atual e pedido.
+    pub fn check_reload(&self, new: &GameConfig) -> Result<Vec<String>, String> {
+        new.validate()?;
+
+        let changed = self.diff(new);
+        let (before, after) = (self.flatten(), new.flatten());
+
+        let errors: Vec<String> = changed
+            .iter()
+            .filter(|key| RESTART_ONLY.iter().any(|fixed| *key == fixed || key.starts_with(&format!("{}.", fixed))))
+            .map(|key| format!("{} só muda reiniciando o servidor (atual {}, pedido {})", key, before[key], after[key]))
+            .collect();
+
+        if errors.is_empty() {
+            return Ok(changed);
+        }
+        Err(errors.join("\n"))
+    }
+
+    fn flatten(&self) -> BTreeMap<String, toml::Value> {
+        let mut keys = BTreeMap::new();
+        if let Ok(root) = toml::Value::try_from(self) {
+            flatten_into("", root, &mut keys);
+        }
+        keys
+    }
+
+    /// Duração em segundos convertida pra ticks.
+    pub fn ticks(&self, secs: f32) -> u32 {
+        (secs * self.tick_rate as f32) as u32
+    }
+
+    /// Taxa por segundo convertida pra por tick.
+    pub fn per_tick(&self, per_second: f32) -> f32 {
+        per_second / self.tick_rate as f32
+    }
+}
+
+/// Tira `--config` dos argumentos; senão `ASTEROIDS_CONFIG`; senão
+/// `asteroids.toml`, se existir.
+fn config_path(cli: &mut Vec<(String, String)>) -> Option<String> {
+    match cli.iter().position(|(k, _)| k == "config") {
+        Some(i) => Some(cli.remove(i).1),
+        None => std::env::var(format!("{}CONFIG", ENV_PREFIX))
+            .ok()
+            .or_else(|| Path::new(DEFAULT_CONFIG_PATH).exists().then(|| DEFAULT_CONFIG_PATH.to_string())),
+    }
+}
+
+fn flatten_into(prefix: &str, value: toml::Value, keys: &mut BTreeMap<String, toml::Value>) {
+    match value {
+        toml::Value::Table(table) => {
+            for (name, value) in table {
+                let key = if prefix.is_empty() { name } else { format!("{}.{}", prefix, name) };
+                flatten_into(&key, value, keys);
+            }
+        }
+        value => {
+            keys.insert(prefix.to_string(), value);
+        }
+    }
+}
+
+/// `--chave valor` ou `--chave=valor`; `-` no nome vira `_`.
+fn parse_args(args: &[String]) -> Result<Vec<(String, String)>, String> {
+    let mut pairs = Vec::new();
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        let name = arg
+            .strip_prefix("--")
+            .ok_or_else(|| format!("argumento inesperado: {}", arg))?;
+
+        let (key, value) = match name.split_once('=') {
+            Some((key, value)) => (key.to_string(), value.to_string()),
+            None => {
+                let value = iter.next().ok_or_else(|| format!("faltou o valor de --{}", name))?;
+                (name.to_string(), value.clone())
+            }
+        };
+
+        pairs.push((key.replace('-', "_"), value));
+    }
+
+    Ok(pairs)
+}
+
+/// `ASTEROIDS_SHIP__MAX_SPEED` → `ship.max_speed`. `PORT` também vale,
+/// que é o que as hospedagens definem.
+fn env_overrides() -> Vec<(String, String)> {
+    let mut pairs: Vec<(String, String)> = std::env::vars()
+        .filter_map(|(name, value)| {
+            let key = name.strip_prefix(ENV_PREFIX)?;
+            if key == "CONFIG" {
+                return None;
+            }
+            Some((key.to_lowercase().replace("__", "."), value))
+        })
+        .collect();
+
+    if let Ok(port) = std::env::var("PORT") {
+        pairs.insert(0, ("port".to_string(), port));
+    }
+
+    pairs
+}
+
+fn parse_value(raw: &str) -> toml::Value {
+    toml::from_str::<toml::Table>(&format!("v = {}", raw))
+        .ok()
+        .and_then(|mut table| table.remove("v"))
+        .unwrap_or_else(|| toml::Value::String(raw.to_string()))
+}
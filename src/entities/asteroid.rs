@@ -1,46 +1,50 @@
-use uuid::Uuid;
-
-use crate::entities::traits::collision_object::CollisionObject;
-use crate::entities::traits::warp_object::WarpObject;
-use crate::entities::hitbox::{HitBox, EntityKind, LAYER_PLAYER, LAYER_BULLET};
+use crate::entities::hitbox::{EntityKind, Point, LAYER_PLAYER, LAYER_BULLET};
+use crate::entities::polygon::Polygon;
+use crate::types::EntityId;
+use crate::world::World;
+use crate::world::components::{Collider, Spin, Transform, Velocity};
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum AsteroidType {
     SMALL,
     MEDIUM,
     BIG
 }
 
+impl AsteroidType {
+    pub fn radius(&self) -> u8 {
+        match self {
+            AsteroidType::BIG => 35,
+            AsteroidType::MEDIUM => 25,
+            AsteroidType::SMALL => 15,
+        }
+    }
+
+    /// Proporcional à área; o pequeno pesa 1.
+    pub fn mass(&self) -> f32 {
+        let r = self.radius() as f32 / AsteroidType::SMALL.radius() as f32;
+        r * r
+    }
+}
+
+/// Asteroide ainda fora do mundo: `spawn` monta os componentes.
 #[derive(Clone)]
 pub struct Asteroid {
-    pub id: Uuid,
     pub x: f32,
     pub y: f32,
     pub radius: u8,
     pub size: AsteroidType,
+    /// Contorno serrilhado, sorteado no nascimento; vai pro cliente no spawn.
+    pub shape: Polygon,
     v: f32,
-    angle: f32
-}
-
-
-impl CollisionObject for Asteroid {
-
-    fn hitbox(&self) -> HitBox {
-        HitBox::circle(
-            self.id,
-            EntityKind::Asteroid,
-            (self.x, self.y),
-            self.radius as f32,
-            LAYER_PLAYER | LAYER_BULLET,
-        )
-    }
-}
-
-impl WarpObject for Asteroid {
-    fn position(&self) -> (f32, f32) {
-        (self.x, self.y)
-    }
+    angle: f32,
+    // giro por tick; menor gira mais rápido
+    spin: f32,
+    // velocidade herdada do pai (zero pra quem nasce da onda)
+    drift: Velocity,
 }
 
 impl Asteroid {
@@ -52,11 +56,7 @@ impl Asteroid {
 
     /// Teste/benchmark: RNG injetado, ângulo reproduzível.
     pub fn with_rng(x: f32, y: f32, size: AsteroidType, rng: &mut impl Rng) -> Asteroid {
-        let r = match size {
-            AsteroidType::BIG => 35,
-            AsteroidType::MEDIUM => 25,
-            AsteroidType::SMALL => 15,
-        };
+        let r = size.radius();
 
         let v = match size {
             AsteroidType::BIG => 2.0,
@@ -64,17 +64,58 @@ impl Asteroid {
             AsteroidType::SMALL => 6.0,
         };
 
-        Asteroid {id: Uuid::new_v4(), x, y, radius: r, v, size, angle: rng.random_range(0.0..6.28) }
+        let max_spin = match size {
+            AsteroidType::BIG => 0.02,
+            AsteroidType::MEDIUM => 0.035,
+            AsteroidType::SMALL => 0.05,
+        };
+
+        let angle = rng.random_range(0.0..std::f32::consts::TAU);
+        let shape = Self::outline(r as f32, rng);
+        let spin = rng.random_range(-max_spin..max_spin);
+        Asteroid {x, y, radius: r, shape, v, size, angle, spin, drift: Velocity::default() }
     }
 
-    pub fn update(&mut self) {
-        self.x += self.v*f32::cos(self.angle);
-        self.y += self.v*f32::sin(self.angle);
-        
-        (self.x, self.y) = self.warp();
+    /// 7 a 11 vértices com ângulo e distância tremidos em volta de `radius`.
+    /// O tremor do ângulo é menor que meio passo, então a ordem se mantém e
+    /// o contorno continua estrelado (ver `Polygon`).
+    fn outline(radius: f32, rng: &mut impl Rng) -> Polygon {
+        let sides = rng.random_range(7..=11);
+        let step = std::f32::consts::TAU / sides as f32;
+
+        let points: Vec<Point> = (0..sides)
+            .map(|i| {
+                let angle = (i as f32 + rng.random_range(-0.3..0.3)) * step;
+                let dist = radius * rng.random_range(0.8..1.2);
+                Point { x: dist * angle.cos(), y: dist * angle.sin() }
+            })
+            .collect();
+
+        Polygon::new(&points)
+    }
+
+    /// Multiplica a velocidade base do tier (dificuldade da onda).
+    pub fn with_speed_scale(mut self, scale: f32) -> Asteroid {
+        self.v *= scale;
+        self
     }
 
-    pub fn to_json(&self, ) -> String {
-        format!("{{\"id\": \"{}\", \"radius\": \"{}\", \"x\": {}, \"y\": {} }}", self.id, self.radius, self.x, self.y)
+    /// Filho de uma divisão: sai em `heading` com a velocidade do tier,
+    /// somada à do pai (`drift`).
+    pub fn launched(mut self, heading: f32, drift: Velocity) -> Asteroid {
+        self.angle = heading;
+        self.drift = drift;
+        self
     }
-}
\ No newline at end of file
+
+    pub fn spawn(self, world: &mut World) -> EntityId {
+        let own = Velocity::from_angle(self.v, self.angle);
+        let id = world.spawn(EntityKind::Asteroid);
+        world.transforms.insert(id, Transform::new(self.x, self.y, 0.0));
+        world.velocities.insert(id, Velocity { vx: own.vx + self.drift.vx, vy: own.vy + self.drift.vy });
+        world.spins.insert(id, Spin { rate: self.spin });
+        world.colliders.insert(id, Collider::polygon(EntityKind::Asteroid, self.shape, LAYER_PLAYER | LAYER_BULLET));
+        world.tiers.insert(id, self.size);
+        id
+    }
+}
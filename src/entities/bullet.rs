@@ -1,77 +1,83 @@
-use uuid::Uuid;
-
-use crate::types::{ClientId, TICK_RATE};
-use crate::entities::traits::collision_object::CollisionObject;
-use crate::entities::traits::warp_object::WarpObject;
-use crate::entities::hitbox::{HitBox, EntityKind, LAYER_PLAYER, LAYER_ASTEROID};
+use crate::types::{ClientId, EntityId, TeamId};
+use crate::config::WeaponConfig;
+use crate::entities::hitbox::{EntityKind, LAYER_PLAYER, LAYER_ASTEROID, LAYER_UFO};
+use crate::entities::weapon::WeaponKind;
+use crate::world::World;
+use crate::world::components::{Collider, Homing, Lifetime, Owner, Projectile, Transform, Velocity};
 
+/// Tiro saindo da nave, ainda fora do mundo: `spawn` monta os componentes.
 #[derive(Clone)]
 pub struct Bullet {
-    pub id: Uuid,
     pub player_id: ClientId,
     pub x: f32,
     pub y: f32,
     pub v: f32,
     pub angle: f32,
-    ttl: u32
-}
-
-
-impl CollisionObject for Bullet {
-    
-    fn hitbox(&self) -> HitBox {
-        HitBox::circle(
-            self.id,
-            EntityKind::Bullet,
-            (self.x, self.y),
-            5.0,
-            LAYER_PLAYER | LAYER_ASTEROID,
-        )
-    }
-}
-
-impl WarpObject for Bullet {
-    fn position(&self) -> (f32, f32) {
-        (self.x, self.y)
-    }
+    pub team: Option<TeamId>,
+    pub weapon: WeaponKind,
+    pub damage: f32,
+    // radianos por tick atrás do alvo; 0 = reto
+    pub turn_rate: f32,
+    ttl: u32,
+    // tiro de disco voador: sem dono e não acerta outro disco
+    hostile: bool,
 }
 
 
 impl Bullet {
 
-    pub fn new(player_id: ClientId, x0: f32, y0: f32, v0: f32, angle: f32) -> Bullet {
+    /// `v` em pixels por tick e `ttl` em ticks (a nave já converte da config).
+    /// Sai como bala do canhão padrão; `with_weapon` troca.
+    pub fn new(player_id: ClientId, x0: f32, y0: f32, v: f32, angle: f32, ttl: u32) -> Bullet {
         Bullet{ 
-            id: Uuid::new_v4(),
-            player_id: player_id,
+            player_id,
             x: x0, 
             y: y0, 
-            angle: angle,
-            v: v0 + (450.0 / TICK_RATE as f32),
-            ttl: 5*TICK_RATE as u32,
+            angle,
+            team: None,
+            weapon: WeaponKind::Cannon,
+            damage: WeaponConfig::cannon().damage,
+            turn_rate: 0.0,
+            v,
+            ttl,
+            hostile: false,
         }
     }
 
-    pub fn update(&mut self) {
-        self.x += self.v*f32::cos(self.angle);
-        self.y += self.v*f32::sin(self.angle);
-
-        // Warp
-        (self.x, self.y) = self.warp();
-
-        if self.ttl > 0 {
-            self.ttl -= 1;
-        }
+    /// Tiro de disco voador. `player_id` fica nulo e nunca vira dono.
+    pub fn hostile(x0: f32, y0: f32, v: f32, angle: f32, ttl: u32, damage: f32) -> Bullet {
+        let mut bullet = Bullet::new(ClientId::nil(), x0, y0, v, angle, ttl);
+        bullet.damage = damage;
+        bullet.hostile = true;
+        bullet
     }
 
-    pub fn is_destroyed(&self, ) -> bool {
-        self.ttl == 0
+    pub fn with_weapon(mut self, weapon: WeaponKind, damage: f32, turn_rate: f32) -> Bullet {
+        self.weapon = weapon;
+        self.damage = damage;
+        self.turn_rate = turn_rate;
+        self
     }
 
-    pub fn destroy(&mut self, ) {
-        self.ttl=0;
+    /// Distância que percorre até sumir (alcance do laser).
+    pub fn range(&self) -> f32 {
+        self.v * self.ttl as f32
     }
 
-    pub fn to_json(&self, ) -> String {
-        format!("{{\"id\": \"{}\", \"player_id\": \"{}\", \"x\": {}, \"y\": {}, \"angle\": {} }}", self.id, self.player_id, self.x, self.y, self.angle)
+    pub fn spawn(self, world: &mut World) -> EntityId {
+        let id = world.spawn(EntityKind::Bullet);
+        world.transforms.insert(id, Transform::new(self.x, self.y, self.angle));
+        world.velocities.insert(id, Velocity::from_angle(self.v, self.angle));
+        world.lifetimes.insert(id, Lifetime { ticks: self.ttl });
+        let mask = if self.hostile { LAYER_PLAYER | LAYER_ASTEROID } else { LAYER_PLAYER | LAYER_ASTEROID | LAYER_UFO };
+        world.colliders.insert(id, Collider::circle(EntityKind::Bullet, self.weapon.radius(), mask));
+        if !self.hostile {
+            world.owners.insert(id, Owner { player: self.player_id, team: self.team });
+        }
+        world.projectiles.insert(id, Projectile { weapon: self.weapon, damage: self.damage, hostile: self.hostile });
+        if self.turn_rate > 0.0 {
+            world.homing.insert(id, Homing { turn_rate: self.turn_rate });
+        }
+        id
     }
-}
\ No newline at end of file
+}
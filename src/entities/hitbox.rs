@@ -1,15 +1,20 @@
-use uuid::Uuid;
+use crate::entities::polygon::{self, Polygon};
+use crate::types::{ClientId, EntityId, TeamId};
 
-// Collision layers (1 bit cada). Com 3 tipos de entidade, u8 sobra.
+// Collision layers (1 bit cada). Com 5 tipos de entidade, u8 sobra.
 pub const LAYER_PLAYER: u8 = 1 << 0;
 pub const LAYER_BULLET: u8 = 1 << 1;
 pub const LAYER_ASTEROID: u8 = 1 << 2;
+pub const LAYER_POWERUP: u8 = 1 << 3;
+pub const LAYER_UFO: u8 = 1 << 4;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum EntityKind {
     Player,
     Bullet,
     Asteroid,
+    PowerUp,
+    Ufo,
 }
 
 impl EntityKind {
@@ -19,6 +24,8 @@ impl EntityKind {
             EntityKind::Player => LAYER_PLAYER,
             EntityKind::Bullet => LAYER_BULLET,
             EntityKind::Asteroid => LAYER_ASTEROID,
+            EntityKind::PowerUp => LAYER_POWERUP,
+            EntityKind::Ufo => LAYER_UFO,
         }
     }
 
@@ -27,58 +34,101 @@ impl EntityKind {
             EntityKind::Bullet => 0,
             EntityKind::Player => 1,
             EntityKind::Asteroid => 2,
+            EntityKind::PowerUp => 3,
+            EntityKind::Ufo => 4,
         }
     }
 }
 
-#[derive(Clone, Copy)]
+/// Dono do HitBox: jogador pela sessão, o resto pelo id do mundo.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BodyId {
+    Player(ClientId),
+    Entity(EntityId),
+}
+
+impl From<ClientId> for BodyId {
+    fn from(id: ClientId) -> Self {
+        BodyId::Player(id)
+    }
+}
+
+impl From<EntityId> for BodyId {
+    fn from(id: EntityId) -> Self {
+        BodyId::Entity(id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Point {
     pub x: f32,
     pub y: f32,
 }
 
 /// Geometria relativa ao `pos` do HitBox (sem posição absoluta).
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Shape {
     Circle { radius: f32 },
+    Polygon(Polygon),
+}
+
+impl Shape {
+    /// Raio do círculo que envolve a forma.
+    pub fn radius(&self) -> f32 {
+        match self {
+            Shape::Circle { radius } => *radius,
+            Shape::Polygon(polygon) => polygon.radius(),
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
 pub struct HitBox {
-    pub id: Uuid,         // id da entidade dona, pra remoção/resolução
+    pub id: BodyId,       // id da entidade dona, pra remoção/resolução
     pub kind: EntityKind, // tipo da entidade (layer derivado dele)
     pub pos: Point,       // posição absoluta, fonte única
     pub shape: Shape,     // geometria relativa ao pos
     pub mask: u8,         // com quais layers essa entidade colide
+    pub team: Option<TeamId>, // aliados não colidem entre si
 }
 
 impl HitBox {
-    pub fn circle(id: Uuid, kind: EntityKind, pos: (f32, f32), radius: f32, mask: u8) -> HitBox {
+    pub fn circle(id: impl Into<BodyId>, kind: EntityKind, pos: (f32, f32), radius: f32, mask: u8) -> HitBox {
         HitBox {
-            id,
+            id: id.into(),
             kind,
             pos: Point { x: pos.0, y: pos.1 },
             shape: Shape::Circle { radius },
             mask,
+            team: None,
         }
     }
 
-    /// Só testa colisão se ambos os lados se importam com o layer do outro.
+    pub fn with_team(mut self, team: Option<TeamId>) -> HitBox {
+        self.team = team;
+        self
+    }
+
+    /// Só testa colisão se ambos os lados se importam com o layer do outro
+    /// e não estão no mesmo time.
     pub fn should_collide(&self, other: &HitBox) -> bool {
-        (self.mask & other.kind.layer() != 0) && (other.mask & self.kind.layer() != 0)
+        let layers = (self.mask & other.kind.layer() != 0) && (other.mask & self.kind.layer() != 0);
+        let allies = self.team.is_some() && self.team == other.team;
+        layers && !allies
     }
 
-    /// Despacha a colisão por par de formas. Por enquanto só círculo×círculo.
+    /// Despacha a colisão por par de formas.
     pub fn intersects(&self, other: &HitBox) -> bool {
-        match (self.shape, other.shape) {
+        match (&self.shape, &other.shape) {
             (Shape::Circle { radius: r1 }, Shape::Circle { radius: r2 }) => {
                 HitBox::circle_circle_colision(
-                    (self.pos, r1), 
-                    (other.pos, r2)
+                    (self.pos, *r1), 
+                    (other.pos, *r2)
                 )
             }
-
-            _ => false
+            (Shape::Circle { radius }, Shape::Polygon(p)) => polygon::circle_polygon(self.pos, *radius, other.pos, p),
+            (Shape::Polygon(p), Shape::Circle { radius }) => polygon::circle_polygon(other.pos, *radius, self.pos, p),
+            (Shape::Polygon(a), Shape::Polygon(b)) => polygon::polygon_polygon(self.pos, a, other.pos, b),
         }
     }
 
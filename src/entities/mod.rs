@@ -2,4 +2,8 @@ pub mod traits;
 pub mod asteroid;
 pub mod player;
 pub mod bullet;
-pub mod hitbox;
\ No newline at end of file
+pub mod hitbox;
+pub mod polygon;
+pub mod powerup;
+pub mod ufo;
+pub mod weapon;
\ No newline at end of file
@@ -1,8 +1,14 @@
 use crate::entities::bullet::Bullet;
 use crate::entities::traits::warp_object::WarpObject;
 use crate::entities::traits::collision_object::CollisionObject;
-use crate::entities::hitbox::{HitBox, EntityKind, LAYER_BULLET, LAYER_ASTEROID};
-use crate::types::{ClientId, TICK_RATE, WORLD_SIZE};
+use crate::entities::hitbox::{HitBox, EntityKind, LAYER_BULLET, LAYER_ASTEROID, LAYER_POWERUP, LAYER_UFO};
+use crate::config::{GameConfig, PowerUpConfig};
+use crate::entities::asteroid::AsteroidType;
+use crate::entities::powerup::PowerUpKind;
+use crate::entities::weapon::{Weapon, WeaponKind};
+use crate::events::DeathCause;
+use crate::types::{ClientId, TeamId};
+use crate::world::components::{Transform, Velocity};
 use rand::Rng;
 
 #[derive(PartialEq, Clone, Debug)]
@@ -14,24 +20,86 @@ pub enum CMD {
     NONE
 }
 
+/// De onde veio o dano; quanto vale sai de `config.damage`, menos o das
+/// armas, que o projétil já traz.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Damage {
+    /// Projétil ou laser, com o dono e o dano dele.
+    Bullet(Option<ClientId>, f32),
+    Asteroid(AsteroidType),
+    /// Trombada com a física ligada, pela velocidade de aproximação.
+    Collision(f32),
+    /// Tiro ou trombada de disco voador.
+    Ufo,
+}
+
+impl Damage {
+    pub fn amount(&self, config: &GameConfig) -> f32 {
+        match *self {
+            Damage::Bullet(_, amount) => amount,
+            Damage::Asteroid(tier) => config.damage.asteroid(tier),
+            Damage::Collision(closing) => closing * config.damage.collision,
+            Damage::Ufo => config.ufos.damage,
+        }
+    }
+
+    pub fn cause(&self) -> DeathCause {
+        match self {
+            Damage::Bullet(..) => DeathCause::Bullet,
+            Damage::Asteroid(_) | Damage::Collision(_) => DeathCause::Asteroid,
+            Damage::Ufo => DeathCause::Ufo,
+        }
+    }
+
+    /// Jogador a quem a fonte é creditada.
+    pub fn by(&self) -> Option<ClientId> {
+        match *self {
+            Damage::Bullet(owner, _) => owner,
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Player {
-    x: f32,
-    y: f32,
-    angle: f32,
-    vx: f32,
-    vy: f32,
+    // mesmos componentes das entidades do `World`; a nave fica fora dele
+    // porque é indexada pela sessão e pilotada pelo input
+    transform: Transform,
+    velocity: Velocity,
     turn_speed: f32,
     acceleration: f32,
     deceleration: f32,
+    max_speed: f32,
     input_buffer: Vec<CMD>,
     buffer_size: usize,
     client_id: ClientId,
+    team: Option<TeamId>,
     is_destroyed: bool,
+    // ticks desde o último comando de verdade (AFK)
+    idle_ticks: u32,
+    // o escudo gasta antes da vida e recarrega depois de um tempo sem dano
+    health: f32,
+    max_health: f32,
+    shield: f32,
+    max_shield: f32,
+    shield_regen: f32,
+    shield_delay: u32,
+    shield_wait: u32,
+    // fonte do último dano; é quem leva o crédito da morte
+    last_attacker: Option<ClientId>,
+    // ticks até voltar, com a nave destruída
+    respawn_in: u32,
+    // power-ups ativos com os ticks que faltam; o escudo do power-up gasta
+    // antes do normal e some junto com o efeito
+    effects: Vec<(PowerUpKind, u32)>,
+    bonus_shield: f32,
+    powerups: PowerUpConfig,
+    tick_rate: f32,
 
     score: u32,
-    shot_cooldown: u32,
+    // ticks desde o último disparo
     shot_counter: u32,
+    weapon: Weapon,
 }
 
 impl CollisionObject for Player {
@@ -40,48 +108,84 @@ impl CollisionObject for Player {
         HitBox::circle(
             self.client_id,
             EntityKind::Player,
-            (self.x, self.y),
+            (self.transform.x, self.transform.y),
             10.0,
-            LAYER_BULLET | LAYER_ASTEROID,
-        )
+            LAYER_BULLET | LAYER_ASTEROID | LAYER_POWERUP | LAYER_UFO,
+        ).with_team(self.team)
     }
 }
 
 impl WarpObject for Player {
     fn position(&self) -> (f32, f32) {
-        (self.x, self.y)
+        self.transform.position()
     }
 }
 
 impl Player {
     /// Jogo: criação sem se preocupar com RNG (entropia).
-    pub fn new(client_id: &ClientId) -> Player {
-        Self::with_rng(client_id, &mut rand::rng())
+    pub fn new(client_id: &ClientId, config: &GameConfig) -> Player {
+        Self::with_rng(client_id, config, &mut rand::rng())
     }
 
     /// Teste/benchmark: RNG injetado, posição de spawn reproduzível.
-    pub fn with_rng(client_id: &ClientId, rng: &mut impl Rng) -> Player {
-
-        let tick = TICK_RATE as f32;
-        Player {
-            x: rng.random_range(0.0..(WORLD_SIZE as f32)),
-            y: rng.random_range(0.0..(WORLD_SIZE as f32)),
-            angle: 0.0,
-            vx: 0.0,
-            vy: 0.0,
-            turn_speed: 2.0 / tick,
-            acceleration: 7.0 / tick,
-            deceleration: 2.0 / tick,
+    pub fn with_rng(client_id: &ClientId, config: &GameConfig, rng: &mut impl Rng) -> Player {
+
+        let world = config.world_size as f32;
+        let mut player = Player {
+            transform: Transform::new(rng.random_range(0.0..world), rng.random_range(0.0..world), 0.0),
+            velocity: Velocity::default(),
+            turn_speed: 0.0,
+            acceleration: 0.0,
+            deceleration: 0.0,
+            max_speed: 0.0,
             input_buffer: vec![],
             buffer_size: 2,
-            client_id: client_id.clone(),
+            client_id: *client_id,
+            team: None,
 
-            shot_cooldown: (0.4 * tick) as u32,
-            shot_counter: (1.0 * tick) as u32,
+            shot_counter: config.ticks(1.0),
+            weapon: Weapon::new(config.weapons.default, config),
 
             score: 0,
             is_destroyed: false,
-        }
+            idle_ticks: 0,
+            health: 0.0,
+            max_health: 0.0,
+            shield: 0.0,
+            max_shield: 0.0,
+            shield_regen: 0.0,
+            shield_delay: 0,
+            shield_wait: 0,
+            last_attacker: None,
+            respawn_in: 0,
+            effects: Vec::new(),
+            bonus_shield: 0.0,
+            powerups: config.powerups.clone(),
+            tick_rate: config.tick_rate as f32,
+        };
+        player.apply_tuning(config);
+        player.health = player.max_health;
+        player.shield = player.max_shield;
+        player
+    }
+
+    /// Valores de pilotagem e tiro da config (`ship`, `weapons`). Chamado ao
+    /// nascer e de novo quando a config é recarregada com a nave viva.
+    pub fn apply_tuning(&mut self, config: &GameConfig) {
+        let ship = &config.ship;
+        self.turn_speed = config.per_tick(ship.turn_speed);
+        self.acceleration = config.per_tick(ship.acceleration);
+        self.deceleration = config.per_tick(ship.deceleration);
+        self.max_speed = ship.max_speed;
+        self.weapon.apply_tuning(config);
+
+        self.max_health = ship.health;
+        self.max_shield = ship.shield;
+        self.shield_regen = config.per_tick(ship.shield_regen);
+        self.shield_delay = config.ticks(ship.shield_delay_secs);
+        self.health = self.health.min(self.max_health);
+        self.shield = self.shield.min(self.max_shield);
+        self.powerups = config.powerups.clone();
     }
 
     pub fn push_command(&mut self, cmd: CMD) {
@@ -91,24 +195,108 @@ impl Player {
         self.input_buffer.push(cmd);
     }
 
+    /// Comando de verdade chegou (não conta pacote vazio).
+    pub fn mark_active(&mut self) {
+        self.idle_ticks = 0;
+    }
+
+    pub fn idle_ticks(&self) -> u32 {
+        self.idle_ticks
+    }
+
     pub fn clear_input_buffer(&mut self, ) {
         self.input_buffer = vec![CMD::NONE];
     }
 
     pub fn can_shoot(&self,) -> bool {
-        self.shot_cooldown <= self.shot_counter
+        self.cooldown() <= self.shot_counter && self.weapon.ready()
+    }
+
+    /// Recarga do tiro em ticks, com o `rapid_fire` aplicado.
+    fn cooldown(&self) -> u32 {
+        let cooldown = self.weapon.cooldown();
+        if self.has_effect(PowerUpKind::RapidFire) {
+            return (cooldown as f32 * self.powerups.rapid_fire_cooldown) as u32;
+        }
+        cooldown
+    }
+
+    pub fn weapon(&self) -> &Weapon {
+        &self.weapon
+    }
+
+    /// Troca de arma; a nova espera uma recarga inteira antes do 1º tiro.
+    pub fn equip(&mut self, kind: WeaponKind, config: &GameConfig) {
+        if self.weapon.kind() == kind {
+            return;
+        }
+        self.weapon.switch(kind, config);
+        self.shot_counter = 0;
+    }
+
+    fn max_speed(&self) -> f32 {
+        if self.has_effect(PowerUpKind::SpeedBoost) {
+            return self.max_speed * self.powerups.speed_boost;
+        }
+        self.max_speed
+    }
+
+    pub fn has_effect(&self, kind: PowerUpKind) -> bool {
+        self.effects.iter().any(|(k, _)| *k == kind)
+    }
+
+    /// Liga o efeito por `powerups.duration_secs`; pegar de novo renova.
+    pub fn apply_powerup(&mut self, kind: PowerUpKind) {
+        let ticks = (self.powerups.duration_secs * self.tick_rate) as u32;
+        match self.effects.iter_mut().find(|(k, _)| *k == kind) {
+            Some(effect) => effect.1 = ticks,
+            None => self.effects.push((kind, ticks)),
+        }
+        if kind == PowerUpKind::Shield {
+            self.bonus_shield = self.powerups.shield;
+        }
     }
 
-    pub fn update(&mut self) -> Option<Bullet>{
+    fn tick_effects(&mut self) {
+        for effect in self.effects.iter_mut() {
+            effect.1 = effect.1.saturating_sub(1);
+        }
+        self.effects.retain(|(_, ticks)| *ticks > 0);
+        if !self.has_effect(PowerUpKind::Shield) {
+            self.bonus_shield = 0.0;
+        }
+    }
+
+    /// `wrap` falso prende a nave nas bordas em vez de dar a volta.
+    /// Destruída, a nave só conta o tempo até voltar.
+    pub fn update(&mut self, wrap: bool, world_size: f32) -> Vec<Bullet>{
+
+        if self.is_destroyed {
+            self.respawn_in = self.respawn_in.saturating_sub(1);
+            self.clear_input_buffer();
+            return Vec::new();
+        }
 
+        self.tick_effects();
+        self.recharge_shield();
+        self.weapon.update();
         self.apply_move_commands();
-        self.movement();
+        self.movement(wrap, world_size);
 
-        let bullet = self.apply_fire_commands();
+        let bullets = self.apply_fire_commands();
         self.shot_counter += 1;
+        self.idle_ticks += 1;
         self.clear_input_buffer();
 
-        return bullet;
+        bullets
+    }
+
+    fn recharge_shield(&mut self) {
+        if self.shield_wait > 0 {
+            self.shield_wait -= 1;
+            return;
+        }
+        self.shield = (self.shield + self.shield_regen).min(self.max_shield);
     }
 
     fn apply_move_commands(&mut self,) {
@@ -116,91 +304,182 @@ impl Player {
         for cmd in self.input_buffer.iter() {
             match cmd {
                 CMD::UP => {
-                    let max_speed: f32 = 10.0;
+                    let max_speed = self.max_speed();
+                    let velocity = &mut self.velocity;
                     // Aceleração na direção do ângulo
-                    self.vx += self.acceleration * self.angle.cos();
-                    self.vy += self.acceleration * self.angle.sin();
+                    velocity.vx += self.acceleration * self.transform.angle.cos();
+                    velocity.vy += self.acceleration * self.transform.angle.sin();
 
                     // Limita a velocidade pela magnitude do vetor (cap em qualquer direção)
-                    let speed = (self.vx * self.vx + self.vy * self.vy).sqrt();
+                    let speed = velocity.speed();
                     if speed > max_speed {
-                        self.vx = self.vx / speed * max_speed;
-                        self.vy = self.vy / speed * max_speed;
+                        velocity.vx = velocity.vx / speed * max_speed;
+                        velocity.vy = velocity.vy / speed * max_speed;
                     }
                 }
                 CMD::LEFT => {
-                    self.angle -= self.turn_speed;
+                    self.transform.angle -= self.turn_speed;
                 }
                 CMD::RIGHT => {
-                    self.angle += self.turn_speed;
+                    self.transform.angle += self.turn_speed;
                 }
                 _ => {}
             }
         }
     }
 
-    fn movement(&mut self,) {
+    fn movement(&mut self, wrap: bool, world_size: f32) {
         // Atualiza posição com velocidade
-        self.x += self.vx;
-        self.y += self.vy;
+        self.transform.advance(&self.velocity);
         
         // Desaceleração: reduz a velocidade de forma linear até parar no zero
         // (sem inverter a direção, diferente da fricção multiplicativa).
-        let speed = (self.vx * self.vx + self.vy * self.vy).sqrt();
+        let speed = self.velocity.speed();
         if speed > 0.0 {
             let new_speed = (speed - self.deceleration).max(0.0);
             let factor = new_speed / speed;
-            self.vx *= factor;
-            self.vy *= factor;
+            self.velocity.vx *= factor;
+            self.velocity.vy *= factor;
         }
 
         // Warp
-        (self.x, self.y) = self.warp();
+        self.transform.keep_inside(world_size, wrap);
     }
 
-    fn apply_fire_commands(&mut self) -> Option<Bullet> {
+    fn apply_fire_commands(&mut self) -> Vec<Bullet> {
         
         if !self.can_shoot() {
-            return None;
+            return Vec::new();
         }
 
         if self.input_buffer.contains(&CMD::SHOT){
             return self.fire();
         }
 
-        None
+        Vec::new()
     }
 
-    fn fire(&mut self,) -> Option<Bullet> {
+    /// Disparo da arma equipada, triplicado em leque com o `spread_shot`.
+    fn fire(&mut self,) -> Vec<Bullet> {
 
-        let v0 = f32::sqrt(self.vx*self.vx + self.vy*self.vy);
         self.shot_counter = 0;
 
-        //Knockback
-        // let tick = TICK_RATE as f32;
-        // let knockback = 10.0/tick;
-        // self.vy -= knockback*self.angle.sin();
-        // self.vx -= knockback*self.angle.cos();
+        let spread = self.powerups.spread_angle;
+        let angles: &[f32] = if self.has_effect(PowerUpKind::SpreadShot) { &[-spread, 0.0, spread] } else { &[0.0] };
+        let bullets = self.weapon.fire(self.client_id, self.team, &self.transform, &self.velocity, angles);
+
+        // knockback, depois de a bala herdar a velocidade da nave
+        let knockback = self.weapon.knockback();
+        self.velocity.vx -= knockback * self.transform.angle.cos();
+        self.velocity.vy -= knockback * self.transform.angle.sin();
 
-        Some(Bullet::new(self.client_id, self.x, self.y, v0,  self.angle))
+        bullets
     }
 
     pub fn to_json(&self) -> String {
+        let team = self.team.map_or("null".to_string(), |t| t.to_string());
+        let effects: Vec<String> = self
+            .effects
+            .iter()
+            .map(|(kind, ticks)| format!("{{\"kind\": \"{}\", \"remaining\": {}}}", kind.name(), *ticks as f32 / self.tick_rate))
+            .collect();
+
+        format!("{{ \"id\":\"{}\", \"x\": {}, \"y\":{}, \"angle\": {}, \"is_destroyed\": {}, \"score\": {}, \"team\": {}, \"health\": {}, \"shield\": {}, \"effects\": [{}], \"weapon\": {} }}",
+                self.client_id, self.transform.x, self.transform.y, self.transform.angle, self.is_destroyed, self.score, team, self.health, self.shield(), effects.join(","), self.weapon.to_json())
+    }
+
+    pub fn velocity(&self) -> Velocity {
+        self.velocity
+    }
+
+    /// Posição e velocidade pra física mexer de fora (trombadas).
+    pub fn body_mut(&mut self) -> (&mut Transform, &mut Velocity) {
+        (&mut self.transform, &mut self.velocity)
+    }
 
-        format!("{{ \"id\":\"{}\", \"x\": {}, \"y\":{}, \"angle\": {}, \"is_destroyed\": {}, \"score\": {} }}",
-                self.client_id, self.x, self.y, self.angle, self.is_destroyed, self.score)
+    pub fn health(&self) -> f32 {
+        self.health
     }
 
-    pub fn destroy(&mut self, ){
+    /// Escudo normal mais o do power-up.
+    pub fn shield(&self) -> f32 {
+        self.shield + self.bonus_shield
+    }
+
+    /// Escudo primeiro, depois vida. True quando a vida acabou agora
+    /// (nave já destruída não toma mais dano).
+    pub fn take_damage(&mut self, amount: f32, by: Option<ClientId>) -> bool {
+        if self.is_destroyed {
+            return false;
+        }
+
+        let bonus = amount.min(self.bonus_shield);
+        self.bonus_shield -= bonus;
+        let absorbed = (amount - bonus).min(self.shield);
+        self.shield -= absorbed;
+        self.health = (self.health - (amount - bonus - absorbed)).max(0.0);
+        self.shield_wait = self.shield_delay;
+        self.last_attacker = by;
+        self.health <= 0.0
+    }
+
+    /// Quem causou o último dano, se foi um jogador.
+    pub fn last_attacker(&self) -> Option<ClientId> {
+        self.last_attacker
+    }
+
+    pub fn team(&self) -> Option<TeamId> {
+        self.team
+    }
+
+    pub fn set_team(&mut self, team: Option<TeamId>) {
+        self.team = team;
+    }
+
+    /// Tira a nave de jogo por `respawn_in` ticks (ver `respawn`).
+    pub fn destroy(&mut self, respawn_in: u32){
         self.is_destroyed = true;
+        self.respawn_in = respawn_in;
+        self.velocity = Velocity::default();
+        self.effects.clear();
+        self.bonus_shield = 0.0;
+    }
+
+    pub fn ready_to_respawn(&self) -> bool {
+        self.is_destroyed && self.respawn_in == 0
+    }
+
+    /// Volta em (x, y) parada, com vida, escudo e arma cheios; pontos, time
+    /// e a arma escolhida ficam.
+    pub fn respawn(&mut self, x: f32, y: f32) {
+        self.transform = Transform::new(x, y, 0.0);
+        self.velocity = Velocity::default();
+        self.health = self.max_health;
+        self.shield = self.max_shield;
+        self.shield_wait = 0;
+        self.last_attacker = None;
+        self.weapon.reset();
+        self.is_destroyed = false;
     }
 
     pub fn is_destroyed(&self, ) -> bool{
         self.is_destroyed
     }
 
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+
+    pub fn add_score(&mut self, points: u32) {
+        self.score = self.score.saturating_add(points);
+    }
+
+    pub fn reset_score(&mut self) {
+        self.score = 0;
+    }
+
     pub fn get_id(&self) -> ClientId {
-        self.client_id.clone()
+        self.client_id
     }
 
 }
\ No newline at end of file
@@ -0,0 +1,171 @@
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+use crate::entities::hitbox::Point;
+
+/// Contorno em volta do centro (0, 0), vértices em ângulo crescente.
+/// Pode ser côncavo (asteroide serrilhado), mas precisa ser estrelado: do
+/// centro se vê todo vértice. Aí cada triângulo centro→aresta é convexo e a
+/// colisão testa esses triângulos com SAT (decomposição convexa em leque).
+///
+/// Tamanho fixo pra continuar `Copy` como o resto do `HitBox`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Polygon {
+    points: [Point; Polygon::MAX_POINTS],
+    len: u8,
+    radius: f32,
+}
+
+impl Polygon {
+    pub const MAX_POINTS: usize = 12;
+
+    /// Vértices além de `MAX_POINTS` ficam de fora.
+    pub fn new(points: &[Point]) -> Polygon {
+        let len = points.len().min(Self::MAX_POINTS);
+        let mut polygon = Polygon { points: [Point { x: 0.0, y: 0.0 }; Self::MAX_POINTS], len: len as u8, radius: 0.0 };
+        polygon.points[..len].copy_from_slice(&points[..len]);
+        polygon.radius = polygon.points().iter().map(|p| p.x.hypot(p.y)).fold(0.0, f32::max);
+        polygon
+    }
+
+    pub fn points(&self) -> &[Point] {
+        &self.points[..self.len as usize]
+    }
+
+    /// Raio do círculo que envolve tudo (descarte rápido).
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Mesmo contorno girado em volta do centro (continua estrelado).
+    pub fn rotated(&self, angle: f32) -> Polygon {
+        if angle == 0.0 {
+            return *self;
+        }
+        let (sin, cos) = angle.sin_cos();
+        let mut polygon = *self;
+        for p in polygon.points[..self.len as usize].iter_mut() {
+            *p = Point { x: p.x * cos - p.y * sin, y: p.x * sin + p.y * cos };
+        }
+        polygon
+    }
+
+    /// Triângulos (centro, p[i], p[i+1]) já na posição `at`.
+    fn pieces(&self, at: Point) -> impl Iterator<Item = [Point; 3]> + '_ {
+        let points = self.points();
+        (0..points.len()).map(move |i| {
+            let a = points[i];
+            let b = points[(i + 1) % points.len()];
+            [at, Point { x: at.x + a.x, y: at.y + a.y }, Point { x: at.x + b.x, y: at.y + b.y }]
+        })
+    }
+}
+
+impl Serialize for Polygon {
+    /// `[[x, y], ...]` relativo ao centro.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len as usize))?;
+        for p in self.points() {
+            seq.serialize_element(&[p.x, p.y])?;
+        }
+        seq.end()
+    }
+}
+
+/// Círculo em `center` contra o polígono em `at`.
+pub fn circle_polygon(center: Point, radius: f32, at: Point, polygon: &Polygon) -> bool {
+    if !bounds_overlap(center, radius, at, polygon.radius()) {
+        return false;
+    }
+    polygon.pieces(at).any(|piece| circle_convex(center, radius, &piece))
+}
+
+pub fn polygon_polygon(a_at: Point, a: &Polygon, b_at: Point, b: &Polygon) -> bool {
+    if !bounds_overlap(a_at, a.radius(), b_at, b.radius()) {
+        return false;
+    }
+    a.pieces(a_at).any(|pa| b.pieces(b_at).any(|pb| convex_convex(&pa, &pb)))
+}
+
+/// Raio saindo de `origin` na direção unitária `dir` contra o polígono em
+/// `at`: distância até a primeira aresta cruzada (0 se já sai de dentro).
+pub fn ray_polygon(origin: Point, dir: Point, at: Point, polygon: &Polygon) -> Option<f32> {
+    if polygon.pieces(at).any(|piece| triangle_contains(&piece, origin)) {
+        return Some(0.0);
+    }
+    polygon
+        .pieces(at)
+        .filter_map(|[_, a, b]| ray_segment(origin, dir, a, b))
+        .min_by(f32::total_cmp)
+}
+
+fn cross(a: Point, b: Point) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+/// Vale pros dois sentidos de volta.
+fn triangle_contains(triangle: &[Point; 3], p: Point) -> bool {
+    let side = |a: Point, b: Point| cross(Point { x: b.x - a.x, y: b.y - a.y }, Point { x: p.x - a.x, y: p.y - a.y });
+    let (s0, s1, s2) = (side(triangle[0], triangle[1]), side(triangle[1], triangle[2]), side(triangle[2], triangle[0]));
+    (s0 >= 0.0 && s1 >= 0.0 && s2 >= 0.0) || (s0 <= 0.0 && s1 <= 0.0 && s2 <= 0.0)
+}
+
+/// Aresta paralela ao raio não conta (a vizinha pega).
+fn ray_segment(origin: Point, dir: Point, a: Point, b: Point) -> Option<f32> {
+    let edge = Point { x: b.x - a.x, y: b.y - a.y };
+    let denom = cross(dir, edge);
+    if denom.abs() <= f32::EPSILON {
+        return None;
+    }
+    let to_a = Point { x: a.x - origin.x, y: a.y - origin.y };
+    let t = cross(to_a, edge) / denom;
+    let s = cross(to_a, dir) / denom;
+    (t >= 0.0 && (0.0..=1.0).contains(&s)).then_some(t)
+}
+
+fn bounds_overlap(a: Point, ra: f32, b: Point, rb: f32) -> bool {
+    let (dx, dy) = (a.x - b.x, a.y - b.y);
+    let r = ra + rb;
+    dx * dx + dy * dy <= r * r
+}
+
+/// SAT: separados se algum eixo (normal de aresta) separa as projeções.
+fn convex_convex(a: &[Point], b: &[Point]) -> bool {
+    edge_normals(a).chain(edge_normals(b)).all(|axis| {
+        let (a_min, a_max) = project(a, axis);
+        let (b_min, b_max) = project(b, axis);
+        a_min <= b_max && b_min <= a_max
+    })
+}
+
+/// SAT com círculo: normais das arestas mais o eixo centro→vértice mais perto.
+fn circle_convex(center: Point, radius: f32, polygon: &[Point]) -> bool {
+    let closest = polygon
+        .iter()
+        .map(|p| Point { x: p.x - center.x, y: p.y - center.y })
+        .min_by(|a, b| (a.x * a.x + a.y * a.y).total_cmp(&(b.x * b.x + b.y * b.y)));
+    let to_vertex = closest.and_then(normalized);
+
+    edge_normals(polygon).chain(to_vertex).all(|axis| {
+        let (p_min, p_max) = project(polygon, axis);
+        let c = center.x * axis.x + center.y * axis.y;
+        c - radius <= p_max && p_min <= c + radius
+    })
+}
+
+fn edge_normals(points: &[Point]) -> impl Iterator<Item = Point> + '_ {
+    (0..points.len()).filter_map(move |i| {
+        let a = points[i];
+        let b = points[(i + 1) % points.len()];
+        normalized(Point { x: a.y - b.y, y: b.x - a.x })
+    })
+}
+
+/// Aresta degenerada não vira eixo.
+fn normalized(v: Point) -> Option<Point> {
+    let len = v.x.hypot(v.y);
+    (len > f32::EPSILON).then(|| Point { x: v.x / len, y: v.y / len })
+}
+
+fn project(points: &[Point], axis: Point) -> (f32, f32) {
+    points.iter().map(|p| p.x * axis.x + p.y * axis.y).fold((f32::MAX, f32::MIN), |(min, max), d| (min.min(d), max.max(d)))
+}
@@ -0,0 +1,73 @@
+use crate::entities::hitbox::{EntityKind, LAYER_PLAYER};
+use crate::types::EntityId;
+use crate::world::World;
+use crate::world::components::{Collider, Lifetime, Transform};
+use rand::Rng;
+use serde::Serialize;
+
+/// Efeito que a nave ganha ao encostar no item.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PowerUpKind {
+    /// Recarga do tiro mais curta.
+    RapidFire,
+    /// Três balas por disparo, em leque.
+    SpreadShot,
+    /// Escudo extra enquanto durar.
+    Shield,
+    /// Velocidade máxima maior.
+    SpeedBoost,
+}
+
+impl PowerUpKind {
+    pub const ALL: [PowerUpKind; 4] = [
+        PowerUpKind::RapidFire,
+        PowerUpKind::SpreadShot,
+        PowerUpKind::Shield,
+        PowerUpKind::SpeedBoost,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PowerUpKind::RapidFire => "rapid_fire",
+            PowerUpKind::SpreadShot => "spread_shot",
+            PowerUpKind::Shield => "shield",
+            PowerUpKind::SpeedBoost => "speed_boost",
+        }
+    }
+}
+
+/// Item parado no mapa, ainda fora do mundo: `spawn` monta os componentes.
+#[derive(Clone)]
+pub struct PowerUp {
+    pub x: f32,
+    pub y: f32,
+    pub kind: PowerUpKind,
+    // ticks até sumir se ninguém pegar
+    ttl: u32,
+}
+
+impl PowerUp {
+    pub const RADIUS: f32 = 12.0;
+
+    pub fn new(x: f32, y: f32, kind: PowerUpKind, ttl: u32) -> PowerUp {
+        PowerUp { x, y, kind, ttl }
+    }
+
+    /// Posição e tipo sorteados.
+    pub fn with_rng(world_size: f32, ttl: u32, rng: &mut impl Rng) -> PowerUp {
+        let x = rng.random_range(0.0..world_size);
+        let y = rng.random_range(0.0..world_size);
+        let kind = PowerUpKind::ALL[rng.random_range(0..PowerUpKind::ALL.len())];
+        PowerUp::new(x, y, kind, ttl)
+    }
+
+    pub fn spawn(self, world: &mut World) -> EntityId {
+        let id = world.spawn(EntityKind::PowerUp);
+        world.transforms.insert(id, Transform::new(self.x, self.y, 0.0));
+        world.lifetimes.insert(id, Lifetime { ticks: self.ttl });
+        world.colliders.insert(id, Collider::circle(EntityKind::PowerUp, Self::RADIUS, LAYER_PLAYER));
+        world.powerups.insert(id, self.kind);
+        id
+    }
+}
@@ -1,17 +1,20 @@
-use crate::types::WORLD_SIZE;
-
-
-
 pub trait WarpObject {
 
     fn position(&self) -> (f32, f32);
     
-    fn warp(&self) -> (f32, f32) {
+    fn warp(&self, world_size: f32) -> (f32, f32) {
         let (x, y) = self.position();
 
-        let warp_x = if x < 0.0 { WORLD_SIZE as f32 } else { x%(WORLD_SIZE as f32) };
-        let warp_y = if y < 0.0 { WORLD_SIZE as f32 } else { y%(WORLD_SIZE as f32) };
+        let warp_x = if x < 0.0 { world_size } else { x%world_size };
+        let warp_y = if y < 0.0 { world_size } else { y%world_size };
+
+        (warp_x, warp_y)
+    }
+
+    /// Sem volta: prende a posição nas bordas do mundo.
+    fn clamp(&self, world_size: f32) -> (f32, f32) {
+        let (x, y) = self.position();
 
-        return (warp_x, warp_y);
+        (x.clamp(0.0, world_size), y.clamp(0.0, world_size))
     }
 }
@@ -0,0 +1,28 @@
+use crate::entities::hitbox::{EntityKind, LAYER_BULLET, LAYER_PLAYER};
+use crate::types::EntityId;
+use crate::world::World;
+use crate::world::components::{Collider, Transform, Velocity};
+
+/// Disco voador inimigo: o estado da IA fica aqui e o resto são os
+/// componentes comuns (posição, velocidade, colisor).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ufo {
+    pub health: f32,
+    // ticks até trocar de rumo
+    pub wander_in: u32,
+    // ticks até o próximo tiro
+    pub fire_in: u32,
+}
+
+impl Ufo {
+    pub const RADIUS: f32 = 16.0;
+
+    pub fn spawn(self, world: &mut World, x: f32, y: f32, velocity: Velocity) -> EntityId {
+        let id = world.spawn(EntityKind::Ufo);
+        world.transforms.insert(id, Transform::new(x, y, 0.0));
+        world.velocities.insert(id, velocity);
+        world.colliders.insert(id, Collider::circle(EntityKind::Ufo, Self::RADIUS, LAYER_PLAYER | LAYER_BULLET));
+        world.ufos.insert(id, self);
+        id
+    }
+}
@@ -0,0 +1,269 @@
+use crate::config::{GameConfig, WeaponLimit};
+use crate::entities::bullet::Bullet;
+use crate::types::{ClientId, TeamId};
+use crate::world::components::{Transform, Velocity};
+use serde::{Deserialize, Serialize};
+
+/// Calor em que a arma trava (`weapons.limit = "heat"`).
+pub const MAX_HEAT: f32 = 100.0;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WeaponKind {
+    /// Bala comum.
+    Cannon,
+    /// Raio instantâneo: acerta o primeiro alvo na linha, sem projétil.
+    Laser,
+    /// Fica parada onde caiu até alguém encostar ou o tempo acabar.
+    Mine,
+    /// Vira atrás da nave inimiga mais perto.
+    Missile,
+}
+
+impl WeaponKind {
+    pub const ALL: [WeaponKind; 4] = [WeaponKind::Cannon, WeaponKind::Laser, WeaponKind::Mine, WeaponKind::Missile];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            WeaponKind::Cannon => "cannon",
+            WeaponKind::Laser => "laser",
+            WeaponKind::Mine => "mine",
+            WeaponKind::Missile => "missile",
+        }
+    }
+
+    pub fn is_hitscan(&self) -> bool {
+        *self == WeaponKind::Laser
+    }
+
+    /// Raio do projétil no mundo.
+    pub fn radius(&self) -> f32 {
+        match self {
+            WeaponKind::Cannon | WeaponKind::Laser => 5.0,
+            WeaponKind::Mine => 10.0,
+            WeaponKind::Missile => 6.0,
+        }
+    }
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+
+    /// Soma a velocidade da nave no disparo (a mina e o laser não).
+    fn inherits_velocity(&self) -> bool {
+        matches!(self, WeaponKind::Cannon | WeaponKind::Missile)
+    }
+}
+
+/// Pente de um tipo de arma. Cada tipo tem o seu e ele continua
+/// recarregando guardado, então trocar de arma não enche nada.
+#[derive(Clone, Copy, Default)]
+struct Clip {
+    ammo: u32,
+    size: u32,
+    // ticks até encher de novo (0 = não está recarregando)
+    reloading: u32,
+}
+
+/// Arma equipada na nave: valores da config já em ticks e o estado do
+/// limite (calor ou munição). A recarga entre tiros fica na nave, por
+/// causa do `rapid_fire`.
+#[derive(Clone)]
+pub struct Weapon {
+    kind: WeaponKind,
+    // pixels e radianos por tick
+    speed: f32,
+    ttl: u32,
+    damage: f32,
+    cooldown: u32,
+    projectiles: u32,
+    spread: f32,
+    knockback: f32,
+    turn_rate: f32,
+
+    limit: WeaponLimit,
+    heat_per_shot: f32,
+    cooling: f32,
+    // superaquecida só atira de novo depois de esfriar de todo
+    heat: f32,
+    overheated: bool,
+    // um por tipo, na ordem de `WeaponKind::ALL`
+    clips: [Clip; 4],
+    reload: u32,
+}
+
+impl Weapon {
+    /// Arma nova, fria e com os pentes cheios.
+    pub fn new(kind: WeaponKind, config: &GameConfig) -> Weapon {
+        let mut weapon = Weapon {
+            kind,
+            speed: 0.0,
+            ttl: 0,
+            damage: 0.0,
+            cooldown: 0,
+            projectiles: 1,
+            spread: 0.0,
+            knockback: 0.0,
+            turn_rate: 0.0,
+            limit: WeaponLimit::None,
+            heat_per_shot: 0.0,
+            cooling: 0.0,
+            heat: 0.0,
+            overheated: false,
+            clips: [Clip::default(); 4],
+            reload: 0,
+        };
+        weapon.apply_tuning(config);
+        weapon.reset();
+        weapon
+    }
+
+    /// Valores de `weapons` pro tipo atual; calor e munição ficam.
+    pub fn apply_tuning(&mut self, config: &GameConfig) {
+        let weapons = &config.weapons;
+        let stats = weapons.get(self.kind);
+        self.speed = config.per_tick(stats.speed);
+        self.ttl = config.ticks(stats.ttl_secs).max(1);
+        self.damage = stats.damage;
+        self.cooldown = config.ticks(stats.cooldown_secs);
+        self.projectiles = stats.projectiles.max(1);
+        self.spread = stats.spread;
+        self.knockback = config.per_tick(stats.knockback);
+        self.turn_rate = config.per_tick(stats.turn_rate);
+
+        self.limit = weapons.limit;
+        self.heat_per_shot = stats.heat;
+        self.cooling = config.per_tick(weapons.cooling);
+        self.reload = config.ticks(weapons.reload_secs);
+        for kind in WeaponKind::ALL {
+            let clip = &mut self.clips[kind.index()];
+            clip.size = weapons.get(kind).ammo.max(1);
+            clip.ammo = clip.ammo.min(clip.size);
+        }
+    }
+
+    /// Troca de arma: calor e pentes são da nave e ficam como estavam.
+    pub fn switch(&mut self, kind: WeaponKind, config: &GameConfig) {
+        self.kind = kind;
+        self.apply_tuning(config);
+    }
+
+    /// Fria e com os pentes cheios (nave voltando).
+    pub fn reset(&mut self) {
+        self.heat = 0.0;
+        self.overheated = false;
+        for clip in self.clips.iter_mut() {
+            clip.ammo = clip.size;
+            clip.reloading = 0;
+        }
+    }
+
+    fn clip(&self) -> &Clip {
+        &self.clips[self.kind.index()]
+    }
+
+    pub fn kind(&self) -> WeaponKind {
+        self.kind
+    }
+
+    /// Ticks entre um disparo e outro.
+    pub fn cooldown(&self) -> u32 {
+        self.cooldown
+    }
+
+    /// Empurrão pra trás na nave, em pixels por tick.
+    pub fn knockback(&self) -> f32 {
+        self.knockback
+    }
+
+    pub fn heat(&self) -> f32 {
+        self.heat
+    }
+
+    pub fn ammo(&self) -> u32 {
+        self.clip().ammo
+    }
+
+    /// O limite deixa atirar (a recarga entre tiros é com a nave).
+    pub fn ready(&self) -> bool {
+        match self.limit {
+            WeaponLimit::None => true,
+            WeaponLimit::Heat => !self.overheated,
+            WeaponLimit::Ammo => self.clip().ammo > 0,
+        }
+    }
+
+    /// Um tick: esfria e anda a recarga dos pentes.
+    pub fn update(&mut self) {
+        self.heat = (self.heat - self.cooling).max(0.0);
+        if self.heat <= 0.0 {
+            self.overheated = false;
+        }
+
+        for clip in self.clips.iter_mut().filter(|clip| clip.reloading > 0) {
+            clip.reloading -= 1;
+            if clip.reloading == 0 {
+                clip.ammo = clip.size;
+            }
+        }
+    }
+
+    /// Dispara: gasta o limite e devolve os projéteis, abertos em leque de
+    /// `spread` e repetidos em cada ângulo extra de `offsets` (`spread_shot`).
+    pub fn fire(
+        &mut self,
+        owner: ClientId,
+        team: Option<TeamId>,
+        transform: &Transform,
+        velocity: &Velocity,
+        offsets: &[f32],
+    ) -> Vec<Bullet> {
+        self.spend();
+
+        let v0 = if self.kind.inherits_velocity() { velocity.speed() } else { 0.0 };
+        let step = if self.projectiles > 1 { self.spread / (self.projectiles - 1) as f32 } else { 0.0 };
+        let first = -step * (self.projectiles - 1) as f32 / 2.0;
+
+        offsets
+            .iter()
+            .flat_map(|offset| (0..self.projectiles).map(move |i| offset + first + step * i as f32))
+            .map(|offset| {
+                let angle = transform.angle + offset;
+                let mut bullet = Bullet::new(owner, transform.x, transform.y, v0 + self.speed, angle, self.ttl)
+                    .with_weapon(self.kind, self.damage, self.turn_rate);
+                bullet.team = team;
+                bullet
+            })
+            .collect()
+    }
+
+    fn spend(&mut self) {
+        match self.limit {
+            WeaponLimit::None => {}
+            WeaponLimit::Heat => {
+                self.heat = (self.heat + self.heat_per_shot).min(MAX_HEAT);
+                self.overheated = self.heat >= MAX_HEAT;
+            }
+            WeaponLimit::Ammo => {
+                let reload = self.reload.max(1);
+                let clip = &mut self.clips[self.kind.index()];
+                clip.ammo = clip.ammo.saturating_sub(1);
+                if clip.ammo == 0 {
+                    clip.reloading = reload;
+                }
+            }
+        }
+    }
+
+    /// `ammo` vai null sem o limite de munição.
+    pub fn to_json(&self) -> String {
+        let ammo = match self.limit {
+            WeaponLimit::Ammo => self.clip().ammo.to_string(),
+            _ => "null".to_string(),
+        };
+        format!(
+            "{{\"kind\": \"{}\", \"heat\": {}, \"overheated\": {}, \"ammo\": {}}}",
+            self.kind.name(), self.heat, self.overheated, ammo
+        )
+    }
+}
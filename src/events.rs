@@ -0,0 +1,112 @@
+use serde::Serialize;
+
+use crate::entities::asteroid::AsteroidType;
+use crate::entities::polygon::Polygon;
+use crate::entities::powerup::PowerUpKind;
+use crate::config::AfkAction;
+use crate::types::{ClientId, EntityId};
+
+/// O que matou o jogador.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeathCause {
+    Bullet,
+    Asteroid,
+    Ufo,
+    /// Ficou fora da zona segura (battle royale).
+    Zone,
+}
+
+/// Eventos produzidos pela simulação em cada tick.
+/// Vão para o cliente junto do snapshot (campo `Events`) e servem de
+/// entrada para pontuação, estatísticas e replays.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GameEvent {
+    PlayerSpawned {
+        id: ClientId,
+        x: f32,
+        y: f32,
+    },
+    PlayerLeft {
+        id: ClientId,
+    },
+    PlayerKilled {
+        victim: ClientId,
+        killer: Option<ClientId>,
+        cause: DeathCause,
+    },
+    /// `owner` nulo: tiro de disco voador.
+    BulletFired {
+        id: EntityId,
+        owner: Option<ClientId>,
+    },
+    /// Disparo instantâneo: o raio vai de (x, y) por `length` pixels na
+    /// direção `angle` (até onde acertou, ou o alcance todo).
+    LaserFired {
+        owner: ClientId,
+        x: f32,
+        y: f32,
+        angle: f32,
+        length: f32,
+    },
+    /// `parent` preenchido quando o asteroide nasce de uma divisão. O
+    /// contorno só vai aqui; o snapshot manda a posição.
+    AsteroidSpawned {
+        id: EntityId,
+        tier: AsteroidType,
+        x: f32,
+        y: f32,
+        parent: Option<EntityId>,
+        shape: Polygon,
+    },
+    /// `by` é o jogador responsável (tiro ou colisão), se houver.
+    AsteroidDestroyed {
+        id: EntityId,
+        tier: AsteroidType,
+        by: Option<ClientId>,
+    },
+    PowerUpSpawned {
+        id: EntityId,
+        kind: PowerUpKind,
+        x: f32,
+        y: f32,
+    },
+    PowerUpCollected {
+        id: EntityId,
+        kind: PowerUpKind,
+        by: ClientId,
+    },
+    UfoSpawned {
+        id: EntityId,
+        x: f32,
+        y: f32,
+    },
+    /// `by` é quem derrubou (tiro ou trombada), se foi um jogador.
+    UfoDestroyed {
+        id: EntityId,
+        by: Option<ClientId>,
+    },
+    WaveStarted {
+        wave: u32,
+        asteroids: u32,
+    },
+    RoundEnded {
+        mode: &'static str,
+        winner: Option<ClientId>,
+    },
+    /// Jogador parado há muito tempo; sai em `remove_in` segundos se não mexer.
+    IdleWarning {
+        id: ClientId,
+        remove_in: f32,
+    },
+    /// Jogador tirado por inatividade (`PlayerLeft` vem junto).
+    PlayerIdle {
+        id: ClientId,
+        action: AfkAction,
+    },
+    /// Config recarregada com o jogo rodando; `changed` lista as chaves.
+    ConfigReloaded {
+        changed: Vec<String>,
+    },
+}
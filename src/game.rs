@@ -1,103 +1,542 @@
 use crate::collections::bullet_collection::BulletCollection;
+use crate::entities::bullet::Bullet;
+use std::io::Write;
+
+use crate::config::{AfkAction, GameConfig};
+use crate::replay::{self, Recorder, ReplayInput};
+use crate::entities::hitbox::EntityKind;
+use crate::entities::traits::warp_object::WarpObject;
+use crate::entities::weapon::WeaponKind;
+use crate::events::GameEvent;
 use crate::networking::router::{MovePayload};
-use crate::types::ClientId;
+use crate::types::{ClientId, TeamId};
 
 use crate::collections::asteroid_collection::AsteroidCollection;
+use crate::entities::asteroid::AsteroidType;
 use crate::collections::player_collection::PlayerCollection;
+use crate::collections::powerup_collection::PowerUpCollection;
+use crate::collections::team_collection::TeamCollection;
+use crate::collections::ufo_collection::UfoCollection;
 use crate::systems::collision::CollisionSystem;
+use crate::systems::hitscan::HitscanSystem;
+use crate::systems::homing::HomingSystem;
+use crate::systems::lifetime::LifetimeSystem;
+use crate::systems::movement::MovementSystem;
+use crate::systems::wrap::WrapSystem;
+use crate::systems::waves::WaveDirector;
+use crate::modes::{self, GameMode};
+use crate::world::World;
 
 #[derive(Clone)]
 pub struct GameManager {
     pub players: PlayerCollection,
+    // asteroides, balas e o que mais vier, como componentes
+    pub world: World,
     pub asteroids: AsteroidCollection,
     pub bullets: BulletCollection,
+    pub powerups: PowerUpCollection,
+    pub ufos: UfoCollection,
+    pub teams: TeamCollection,
+    config: GameConfig,
+    // recarregada fora do tick, entra no começo do próximo
+    pending_config: Option<GameConfig>,
+    // ticks simulados desde a criação (não conta os pausados)
+    tick_count: u64,
+    // parados com `afk.action = disconnect`: o servidor fecha o socket
+    idle_kicks: Vec<ClientId>,
+    // None = entropia; guardada pra gravar no replay
+    seed: Option<u64>,
+    // entradas de fora gravadas pra re-simular a partida (replay)
+    recorder: Option<Recorder>,
+    // pausado pelo admin: o tick não anda, o snapshot continua saindo
+    paused: bool,
+    // tick no pool do rayon (feature `parallel`); desligável pra comparar
+    parallel: bool,
+    // regras da sala, escolhidas na criação
+    mode: Box<dyn GameMode>,
+    waves: WaveDirector,
+    // eventos acumulados desde o último snapshot
+    events: Vec<GameEvent>,
+}
+
+impl Default for GameManager {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl GameManager {
     /// Jogo com RNG por entropia (aleatório a cada execução).
     pub fn new() -> Self {
-        Self::build(AsteroidCollection::new(), PlayerCollection::new())
+        Self::with_config(GameConfig::default(), None)
     }
 
     /// Jogo com seed fixa — RNG reproduzível (para testes).
     pub fn with_seed(seed: u64) -> Self {
-        Self::build(
-            AsteroidCollection::seeded(seed),
-            PlayerCollection::seeded(seed.wrapping_add(1)),
-        )
+        Self::with_config(GameConfig::default(), Some(seed))
     }
 
-    fn build(mut asteroids: AsteroidCollection, players: PlayerCollection) -> Self {
-        for _ in 0..asteroids.max_asteroids {
-            asteroids.random_spawn();
-        }
+    /// Sala criada a partir da config: modo, times e limites saem dela.
+    /// A config deve ter passado por `GameConfig::validate`; modo
+    /// desconhecido cai no deathmatch.
+    pub fn with_config(config: GameConfig, seed: Option<u64>) -> Self {
+        let mode = modes::from_config(&config).unwrap_or_else(|| Box::new(modes::deathmatch::Deathmatch::default()));
+
+        let teams = match config.teams {
+            0 => mode.default_teams(),
+            count => TeamCollection::new(count, config.friendly_fire),
+        };
+
+        let mut world = World::with_config(&config);
+        let mut asteroids = AsteroidCollection::with_config(&config, seed);
+        let mut players = PlayerCollection::with_config(&config, seed.map(|s| s.wrapping_add(1)));
+        players.set_respawn(mode.respawns());
+
+        let mut events = Vec::new();
+        let mut waves = WaveDirector::new(config.waves.clone(), config.tick_rate);
+        waves.start_next_wave(&mut asteroids, &mut world, players.alive_count(), &mut events);
 
         Self {
             players,
+            world,
             asteroids,
-            bullets: BulletCollection::new(),
+            bullets: BulletCollection::with_config(&config),
+            powerups: PowerUpCollection::with_config(&config, seed.map(|s| s.wrapping_add(2))),
+            ufos: UfoCollection::with_config(&config, seed.map(|s| s.wrapping_add(3))),
+            teams,
+            config,
+            pending_config: None,
+            tick_count: 0,
+            idle_kicks: Vec::new(),
+            seed,
+            recorder: None,
+            paused: false,
+            parallel: cfg!(feature = "parallel"),
+            mode,
+            waves,
+            events,
+        }
+    }
+
+    pub fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    /// Começa a gravar o replay em `out`. Só numa sala nova (nenhum tick,
+    /// ninguém dentro) e com seed — sem ela não dá pra re-simular.
+    pub fn start_recording(&mut self, out: Box<dyn Write + Send>) -> Result<(), String> {
+        let seed = self.seed.ok_or("replay precisa de seed")?;
+        if self.tick_count > 0 || !self.players.is_empty() || self.pending_config.is_some() {
+            return Err("replay só grava desde o começo da partida".to_string());
+        }
+
+        let recorder = Recorder::start(out, seed, &replay::sanitized(&self.config)).map_err(|e| e.to_string())?;
+        self.recorder = Some(recorder);
+        Ok(())
+    }
+
+    /// Fecha o replay (marca o tick final e descarrega).
+    pub fn finish_recording(&mut self) {
+        if let Some(recorder) = self.recorder.take() {
+            recorder.finish(self.tick_count);
+        }
+    }
+
+    fn record(&self, input: ReplayInput) {
+        if let Some(recorder) = &self.recorder {
+            recorder.record(self.tick_count, &input);
+        }
+    }
+
+    /// Agenda uma config nova pro próximo tick. Só entram valores de ajuste
+    /// de jogo; `GameConfig::check_reload` recusa o que exige reiniciar.
+    /// Devolve as chaves que vão mudar (vazio = nada a fazer).
+    pub fn reload_config(&mut self, config: GameConfig) -> Result<Vec<String>, String> {
+        let changed = self.config.check_reload(&config)?;
+
+        if !changed.is_empty() {
+            self.record(ReplayInput::ReloadConfig(Box::new(replay::sanitized(&config))));
+            self.pending_config = Some(config);
+        }
+        Ok(changed)
+    }
+
+    fn apply_pending_config(&mut self) {
+        let Some(config) = self.pending_config.take() else {
+            return;
+        };
+
+        let changed = self.config.diff(&config);
+        self.players.set_config(&config);
+        self.asteroids.max_asteroids = config.max_asteroids;
+        self.bullets.set_max_bullets(config.max_bullets);
+        self.waves.set_curve(config.waves.clone());
+        // survival liga times sozinho; só mexe se a chave mudou mesmo
+        if changed.iter().any(|key| key.starts_with("powerups.")) {
+            self.powerups.set_config(&config);
+        }
+        if changed.iter().any(|key| key.starts_with("ufos.") || key == "max_bullets") {
+            self.ufos.set_config(&config);
+        }
+        if changed.iter().any(|key| key == "friendly_fire") {
+            self.teams.friendly_fire = config.friendly_fire;
+        }
+        self.config = config;
+
+        tracing::info!(?changed, "config recarregada");
+        self.events.push(GameEvent::ConfigReloaded { changed });
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Liga/desliga o tick paralelo. Sem a feature `parallel` fica sempre serial.
+    pub fn set_parallel(&mut self, parallel: bool) {
+        self.parallel = parallel && cfg!(feature = "parallel");
+    }
+
+    pub fn is_parallel(&self) -> bool {
+        self.parallel
+    }
+
+    pub fn tick_count(&self) -> u64 {
+        self.tick_count
+    }
+
+    /// Asteroide posto à mão (admin). Falha se for grande e o campo estiver cheio.
+    pub fn spawn_asteroid(&mut self, x: f32, y: f32, tier: AsteroidType) -> bool {
+        self.record(ReplayInput::SpawnAsteroid { x, y, tier });
+        self.asteroids.spawn(&mut self.world, x, y, tier, &mut self.events)
+    }
+
+    /// Tira asteroides, balas e discos; a próxima onda vem depois do intervalo.
+    pub fn clear_field(&mut self) {
+        self.record(ReplayInput::ClearField);
+        self.world.clear(EntityKind::Asteroid);
+        self.world.clear(EntityKind::Bullet);
+        self.world.clear(EntityKind::Ufo);
+    }
+
+    pub fn mode_name(&self) -> &'static str {
+        self.mode.name()
+    }
+
+    pub fn wave(&self) -> u32 {
+        self.waves.wave()
+    }
+
+    pub fn add_player(&mut self, client_id: &ClientId) -> Result<ClientId, &'static str> {
+        let id = self.players.add_player(client_id)?;
+        self.record(ReplayInput::Join(id));
+        let team = self.teams.assign(&self.players, None);
+
+        if let Some(player) = self.players.get_player_mut(&id) {
+            player.set_team(team);
+            self.mode.on_join(player);
+            let (x, y) = player.position();
+            self.events.push(GameEvent::PlayerSpawned { id, x, y });
+        }
+
+        Ok(id)
+    }
+
+    /// Troca de time a pedido do jogador.
+    pub fn choose_team(&mut self, client_id: &ClientId, team: TeamId) -> Result<TeamId, &'static str> {
+        if !self.teams.contains(team) {
+            return Err("Time não existe");
         }
+
+        let player = self.players.get_player_mut(client_id).ok_or("Jogador não está no jogo")?;
+        player.set_team(Some(team));
+        self.record(ReplayInput::ChooseTeam(*client_id, team));
+        Ok(team)
+    }
+
+    /// Troca de arma a pedido do jogador.
+    pub fn choose_weapon(&mut self, client_id: &ClientId, kind: WeaponKind) -> Result<WeaponKind, &'static str> {
+        if !self.players.choose_weapon(client_id, kind) {
+            return Err("Jogador não está no jogo");
+        }
+        self.record(ReplayInput::ChooseWeapon(*client_id, kind));
+        Ok(kind)
+    }
+
+    /// Saída pedida de fora (cliente, admin). Gravada no replay.
+    pub fn remove_player(&mut self, client_id: &ClientId) -> bool {
+        let removed = self.drop_player(client_id);
+        if removed {
+            self.record(ReplayInput::Leave(*client_id));
+        }
+        removed
+    }
+
+    /// Saída decidida pela própria simulação; o replay a reproduz sozinho.
+    fn drop_player(&mut self, client_id: &ClientId) -> bool {
+        let removed = self.players.rm_player(client_id);
+
+        if removed {
+            self.mode.on_leave(client_id);
+            self.events.push(GameEvent::PlayerLeft { id: *client_id });
+        }
+
+        removed
+    }
+
+    /// Placar da sala, do maior pro menor.
+    pub fn scoreboard(&self) -> Vec<(ClientId, u32, Option<TeamId>)> {
+        let mut scores: Vec<_> = self.players.iter().map(|p| (p.get_id(), p.score(), p.team())).collect();
+        scores.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        scores
+    }
+
+    /// Sessões que devem ser fechadas por inatividade (esvazia a lista).
+    pub fn drain_idle_kicks(&mut self) -> Vec<ClientId> {
+        std::mem::take(&mut self.idle_kicks)
+    }
+
+    /// Avisa quem está parado e tira quem passou de `afk.idle_secs`.
+    fn check_idle(&mut self) {
+        let afk = &self.config.afk;
+        if afk.idle_secs <= 0.0 {
+            return;
+        }
+
+        let limit = self.config.ticks(afk.idle_secs);
+        let warn_at = limit.saturating_sub(self.config.ticks(afk.warn_secs));
+        let action = afk.action;
+
+        let mut idle = Vec::new();
+        for player in self.players.iter() {
+            let ticks = player.idle_ticks();
+            if ticks >= limit {
+                idle.push(player.get_id());
+            } else if ticks == warn_at {
+                let remove_in = (limit - ticks) as f32 / self.config.tick_rate as f32;
+                self.events.push(GameEvent::IdleWarning { id: player.get_id(), remove_in });
+            }
+        }
+
+        for id in idle {
+            tracing::info!(player = %id, ?action, "jogador parado");
+            self.events.push(GameEvent::PlayerIdle { id, action });
+            self.drop_player(&id);
+            if action == AfkAction::Disconnect {
+                self.idle_kicks.push(id);
+            }
+        }
+    }
+
+    /// Eventos acumulados desde a última chamada (esvazia a fila).
+    pub fn drain_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
     }
 
     pub fn handle_player_command(&mut self, client_id: &ClientId, player_command: &MovePayload) {
+        if self.recorder.is_some() && self.players.get_player_mut(client_id).is_some() {
+            self.record(ReplayInput::Move(*client_id, replay::move_flags(player_command)));
+        }
         self.players.handle_command(client_id, player_command);
     }
 
     pub fn collision(&mut self) {
+        let physics = self.config.physics.enabled.then_some(&self.config.physics);
+
+        #[cfg(feature = "parallel")]
+        if self.parallel {
+            CollisionSystem::run_parallel(
+                &mut self.world,
+                &mut self.players,
+                &mut self.asteroids,
+                self.teams.friendly_fire,
+                physics,
+                &mut self.events,
+            );
+            return;
+        }
+
         CollisionSystem::run(
+            &mut self.world,
             &mut self.players,
-            &mut self.bullets,
             &mut self.asteroids,
+            self.teams.friendly_fire,
+            physics,
+            &mut self.events,
         );
     }
 
     pub fn tick(&mut self, ) {
+        if self.paused {
+            return;
+        }
+
+        self.tick_count += 1;
+        let _span = tracing::info_span!("tick", n = self.tick_count).entered();
 
-        let created_bullets = self.players.update();
-        self.bullets.add_bullets(created_bullets);
-        self.bullets.update();
-        self.asteroids.update();
+        self.apply_pending_config();
+        let first_event = self.events.len();
+
+        // naves (input) -> lasers -> tiros -> mísseis -> movimento -> bordas
+        // -> tempo de vida -> colisão
+        let created_bullets = self.update_players();
+        let respawned = self.players.respawn_ready();
+        self.events.extend(respawned);
+        let (lasers, created_bullets): (Vec<Bullet>, Vec<Bullet>) =
+            created_bullets.into_iter().partition(|b| b.weapon.is_hitscan());
+        HitscanSystem::run(
+            &mut self.world,
+            &mut self.players,
+            &mut self.asteroids,
+            self.teams.friendly_fire,
+            lasers,
+            &mut self.events,
+        );
+        for id in self.bullets.add_bullets(&mut self.world, created_bullets) {
+            if let Some(owner) = self.world.owners.get(id) {
+                self.events.push(GameEvent::BulletFired { id, owner: Some(owner.player) });
+            }
+        }
+        HomingSystem::run(&mut self.world, &self.players, self.teams.friendly_fire);
+        self.move_entities();
+        LifetimeSystem::run(&mut self.world);
         self.collision();
+        self.waves.update(&mut self.asteroids, &mut self.world, self.players.alive_count(), &mut self.events);
+        self.powerups.update(&mut self.world, &mut self.events);
+        self.ufos.update(&mut self.world, &self.players, &mut self.events);
+        self.check_idle();
 
+        self.apply_rules(first_event);
+    }
+
+    fn update_players(&mut self) -> Vec<Bullet> {
+        let wrap = self.mode.wraps_world();
+
+        #[cfg(feature = "parallel")]
+        let bullets = if self.parallel { self.players.update_parallel(wrap) } else { self.players.update(wrap) };
+        #[cfg(not(feature = "parallel"))]
+        let bullets = self.players.update(wrap);
+
+        if let Some(zone) = self.mode.boundary() {
+            self.players.confine(zone);
+        }
+        bullets
+    }
+
+    fn move_entities(&mut self) {
+        let (wrap, zone) = (self.mode.wraps_world(), self.mode.boundary());
+
+        #[cfg(feature = "parallel")]
+        if self.parallel {
+            MovementSystem::run_parallel(&mut self.world);
+            WrapSystem::run_parallel(&mut self.world, wrap, zone);
+            return;
+        }
+
+        MovementSystem::run(&mut self.world);
+        WrapSystem::run(&mut self.world, wrap, zone);
+    }
+
+    /// Repassa ao modo os eventos do tick e encerra a rodada se ele mandar.
+    fn apply_rules(&mut self, first_event: usize) {
+        self.mode.on_tick(&mut self.players, &mut self.events);
+
+        let tick_events = self.events[first_event..].to_vec();
+
+        for event in tick_events.iter() {
+            match event {
+                GameEvent::PlayerKilled { victim, killer, .. } => {
+                    self.mode.on_kill(&mut self.players, victim, killer.as_ref());
+                }
+                GameEvent::AsteroidDestroyed { tier, by, .. } => {
+                    self.mode.on_asteroid_destroyed(&mut self.players, *tier, by.as_ref());
+                }
+                GameEvent::UfoDestroyed { by, .. } => {
+                    self.mode.on_ufo_destroyed(&mut self.players, by.as_ref());
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(outcome) = self.mode.check_round_end(&self.players) {
+            tracing::info!(mode = self.mode.name(), winner = ?outcome.winner, "fim de rodada");
+            self.events.push(GameEvent::RoundEnded { mode: self.mode.name(), winner: outcome.winner });
+            self.mode.on_round_end(&mut self.players);
+            self.world.clear(EntityKind::Ufo);
+            self.waves.restart(&mut self.asteroids, &mut self.world, self.players.alive_count(), &mut self.events);
+        }
     }
 
     pub fn game_info(&self, ) -> String {
         let num_players = self.players.get_players().len();
-        let num_bullets = self.bullets.get_bullets().len();
-        let num_asteroids = self.asteroids.len();
+        let num_bullets = self.world.count(EntityKind::Bullet);
+        let num_asteroids = self.world.count(EntityKind::Asteroid);
 
         format!("Game Info: \n\tPlayers: {} \n\tBullets: {} \n\tAsteroids {}", num_players, num_bullets, num_asteroids)
     }
 
-    fn bullets_to_json(&self, ) -> String {
-        // Inicia a construção dos projeteis
-        let mut json = String::from("\"Bullets\":[");
-        let mut comma = "";
+    fn events_to_json(&mut self, ) -> String {
+        let events = self.drain_events();
+        format!("\"Events\":{}", serde_json::to_string(&events).unwrap_or_else(|_| "[]".into()))
+    }
 
-        for bullet in self.bullets.get_bullets().iter() {
-            let bullet_str = format!("{} {}", comma, &bullet.to_json());
-            json.push_str(&bullet_str);
-            comma = ",";
+    /// Players, Bullets, Asteroids, PowerUps e Ufos do snapshot; no
+    /// paralelo, um por tarefa.
+    fn entities_to_json(&self) -> [String; 5] {
+        #[cfg(feature = "parallel")]
+        if self.parallel {
+            let ((players, bullets), ((asteroids, powerups), ufos)) = rayon::join(
+                || rayon::join(|| self.players.to_json(), || BulletCollection::to_json(&self.world)),
+                || {
+                    rayon::join(
+                        || rayon::join(|| AsteroidCollection::to_json(&self.world), || PowerUpCollection::to_json(&self.world)),
+                        || UfoCollection::to_json(&self.world),
+                    )
+                },
+            );
+            return [players, bullets, asteroids, powerups, ufos];
         }
 
-        json += "]";
-        return json;
+        [
+            self.players.to_json(),
+            BulletCollection::to_json(&self.world),
+            AsteroidCollection::to_json(&self.world),
+            PowerUpCollection::to_json(&self.world),
+            UfoCollection::to_json(&self.world),
+        ]
     }
 
+    /// Snapshot do tick. Esvazia a fila de eventos, que vai junto no campo `Events`.
     pub fn get_game_state(&mut self, ) -> String {
 
         let mut game_state = String::from("{");
 
-        game_state.push_str(&self.players.to_json());
-        game_state.push_str(",");
+        for section in self.entities_to_json() {
+            game_state.push_str(&section);
+            game_state.push(',');
+        }
+
+        game_state.push_str(&format!("\"Mode\":{}", self.mode.to_json()));
+        game_state.push(',');
+
+        game_state.push_str(&format!("\"Wave\":{}", self.waves.wave()));
+        game_state.push(',');
+
+        game_state.push_str(&format!("\"Paused\":{}", self.paused));
+        game_state.push(',');
 
-        // Inicia a construção dos projeteis
-        game_state.push_str(&self.bullets_to_json());
-        game_state.push_str(",");
+        game_state.push_str(&self.teams.to_json(&self.players));
+        game_state.push(',');
 
-        // Asteroids para json
-        game_state.push_str(&self.asteroids.to_json());
-        game_state.push_str("}");
+        game_state.push_str(&self.events_to_json());
+        game_state.push('}');
         game_state
     }
 }
@@ -1,6 +1,14 @@
 pub mod types;
+pub mod config;
+pub mod logging;
 pub mod game;
+pub mod events;
 pub mod collections;
 pub mod entities;
 pub mod networking;
-pub mod systems;
\ No newline at end of file
+pub mod systems;
+pub mod world;
+pub mod modes;
+pub mod replay;
+#[cfg(feature = "metrics")]
+pub mod metrics;
@@ -0,0 +1,18 @@
+use tracing_subscriber::EnvFilter;
+
+use crate::config::{LogConfig, LogFormat};
+
+/// Liga o subscriber global do `tracing` conforme `config.log`. Chamar uma
+/// vez só, logo depois de carregar a config.
+pub fn init(config: &LogConfig) -> Result<(), String> {
+    let filter = EnvFilter::try_new(&config.level).map_err(|e| format!("log.level: {}", e))?;
+    let builder = tracing_subscriber::fmt().with_env_filter(filter);
+
+    let result = match config.format {
+        LogFormat::Pretty => builder.try_init(),
+        // um objeto por linha, com os campos dos spans (client, tick)
+        LogFormat::Json => builder.json().with_current_span(true).with_span_list(true).try_init(),
+    };
+
+    result.map_err(|e| e.to_string())
+}
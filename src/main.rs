@@ -1,129 +1,193 @@
 use std::time::Duration;
 use std::sync::Arc;
 use std::net::SocketAddr;
-use asteroids_server::{game::GameManager, networking::{self, websocket_handler::WebSocketHandler}};
-
-use sysinfo::{
-    System
-};
+use asteroids_server::{config::GameConfig, game::GameManager, networking::{self, admin::Admin, websocket_handler::WebSocketHandler}};
 
 use axum::{
     routing::get,
     Router,
-    extract::ws::{WebSocketUpgrade},
-    http::StatusCode
+    extract::{ConnectInfo, ws::WebSocketUpgrade},
+    http::{HeaderMap, StatusCode}
 };
 
 use tokio::sync::Mutex;
 use tower_http::cors::{CorsLayer, Any};
 
 async fn health_check() -> (StatusCode, &'static str) {
-    println!("Health check!");
     (StatusCode::OK, "OK")
 }
 
-async fn process_info() {
-    let mut sys = System::new_all();
-    let pid = sysinfo::get_current_pid().unwrap();
+#[cfg(feature = "metrics")]
+async fn metrics() -> ([(axum::http::HeaderName, &'static str); 1], String) {
+    // a leitura do processo bloqueia; fora das threads do runtime
+    let text = tokio::task::spawn_blocking(|| asteroids_server::metrics::global().render()).await.unwrap_or_default();
+    ([(axum::http::header::CONTENT_TYPE, "text/plain; version=0.0.4")], text)
+}
 
-    // Primeira leitura (CPU precisa de baseline)
-    sys.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid]), false);
+
+/// Relê a config quando o arquivo muda (olha o mtime a cada segundo) e
+/// manda pro jogo; o que exigir reinício é recusado e fica só no log.
+async fn watch_config(router: networking::router::Router, path: String) {
+    let modified = |path: &str| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+    let mut last = modified(&path);
 
     loop {
-        // Atualiza apenas o processo atual
-        sys.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid]), false);
-
-        if let Some(process) = sys.process(pid) {
-            let ram_mb = process.memory() as f64 / 1024.0;
-            let cpu_usage = process.cpu_usage();
-
-            println!(
-                "Process info: \n\tRAM Used: {:.2} MB \n\tCPU Usage: {:.2}%",
-                ram_mb,
-                cpu_usage
-            );
+        tokio::time::sleep(Duration::from_secs(1)).await;
+
+        let current = modified(&path);
+        if current == last {
+            continue;
         }
+        last = current;
 
-        tokio::time::sleep(Duration::from_secs(1)).await;
+        let result = match GameConfig::load() {
+            Ok(config) => router.reload_config(config).await,
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(changed) if changed.is_empty() => {}
+            Ok(changed) => tracing::info!(%path, ?changed, "config recarregada do arquivo"),
+            Err(e) => tracing::warn!(%path, error = %e, "config do arquivo não aplicada"),
+        }
     }
 }
 
 
-async fn machine_info() {
-    let mut sys = System::new_all();
+/// Espera SIGINT (Ctrl+C) ou SIGTERM (o que a hospedagem manda).
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
 
-    loop {
-        sys.refresh_memory();
-        sys.refresh_cpu_all();
-
-        tokio::time::sleep(Duration::from_millis(500)).await;
-        sys.refresh_cpu_all();
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(_) => std::future::pending::<()>().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
 
-        let total_memory = sys.total_memory();
-        let used_memory = sys.used_memory();
-        let available_memory = sys.available_memory();
-        let memory_percentage = (used_memory as f64 / total_memory as f64) * 100.0;
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+}
 
-        let mut cpu_usage = 0.0;
-        for cpu in sys.cpus() {
-            cpu_usage += cpu.cpu_usage();
-        }
-        let avg_cpu_usage = cpu_usage / sys.cpus().len() as f32;
+/// Depois do sinal: avisa os clientes e espera os envios (com limite).
+/// Quando volta, o axum para de aceitar conexões e o `main` termina.
+async fn graceful_shutdown(server: Arc<WebSocketHandler>) {
+    shutdown_signal().await;
 
-        println!(
-            "Memory info: \n\tUsed Percentage: {:.2}% \tUsed: {} MB \tAvailable: {} MB\tTotal: {} MB \nCPU info:\n\tUsage: {:.2}%",
-            memory_percentage,
-            used_memory / 1024 / 1024,
-            available_memory / 1024 / 1024,
-            total_memory / 1024 / 1024,
-            avg_cpu_usage
-        );
+    let config = server.router().game().lock().await.config().shutdown.clone();
+    tracing::info!(grace_secs = config.grace_secs, "desligando");
 
-        tokio::time::sleep(Duration::from_secs(1)).await;
-    }
+    let reconnect = Some(config.reconnect.as_str()).filter(|r| !r.is_empty());
+    let notified = server.shutdown(reconnect, Duration::from_secs_f32(config.grace_secs)).await;
+    tracing::info!(notified, "clientes avisados");
 }
 
 
 #[tokio::main]
 async fn main() {
 
+    // arquivo (--config / ASTEROIDS_CONFIG / asteroids.toml) < ASTEROIDS_* < --opção
+    let config = match GameConfig::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Configuração inválida:\n{}", e);
+            std::process::exit(1);
+        }
+    };
+    if let Err(e) = asteroids_server::logging::init(&config.log) {
+        eprintln!("Não consegui ligar os logs: {}", e);
+        std::process::exit(1);
+    }
+    let port = config.port;
+    let admin_config = config.admin.clone();
+    let trusted_hops = config.proxy.trusted_hops;
+    let send_timeout = Duration::from_secs_f32(config.heartbeat.timeout_secs);
+
+    // gravar exige seed conhecida; sem replay segue na entropia
+    let replay_path = config.replay.path.clone();
+    let seed = (!replay_path.is_empty()).then(rand::random::<u64>);
+    let mut game = GameManager::with_config(config, seed);
+
+    if !replay_path.is_empty() {
+        let recording = std::fs::File::create(&replay_path)
+            .map_err(|e| e.to_string())
+            .and_then(|file| game.start_recording(Box::new(std::io::BufWriter::new(file))));
+
+        match recording {
+            Ok(()) => tracing::info!(path = %replay_path, seed, "gravando replay"),
+            Err(e) => tracing::error!(path = %replay_path, error = %e, "replay não vai ser gravado"),
+        }
+    }
+    let router = networking::router::Router::new(Arc::new(Mutex::new(game)));
 
-    // tokio::spawn(machine_info());
-    // tokio::spawn(process_info());
+    if let Some(path) = GameConfig::source_path() {
+        tokio::spawn(watch_config(router.clone(), path));
+    }
 
-    let game = GameManager::new();
-    let router = networking::router::Router::new(Arc::new(Mutex::new(game)));
-    let server = Arc::new(WebSocketHandler::new(router));
+    let server = Arc::new(WebSocketHandler::new(router).with_trusted_proxies(trusted_hops).with_send_timeout(send_timeout));
 
-    {
+    let tick_loop = {
         let broadcast_server = Arc::clone(&server);
         tokio::spawn(async move {
             broadcast_server.start().await
-        });
-    }
+        })
+    };
+    let shutdown = graceful_shutdown(Arc::clone(&server));
 
     let cors = CorsLayer::new()
     .allow_origin(Any)
     .allow_methods(Any)
     .allow_headers(Any);
 
-    let app = Router::new()
-        .route("/health", get(health_check))
-        .route("/ws", get(move |ws: WebSocketUpgrade| {
+    let mut app = Router::new()
+        .route("/health", get(health_check));
+
+    #[cfg(feature = "metrics")]
+    {
+        app = app.route("/metrics", get(metrics));
+    }
+
+    // sem segredo o canal de admin nem existe
+    if !admin_config.secret.is_empty() {
+        let admin = Arc::new(Admin::new(Arc::clone(&server), &admin_config));
+        app = app.merge(networking::admin::routes(admin));
+    }
+
+    let app = app
+        .route("/ws", get(move |ws: WebSocketUpgrade, ConnectInfo(addr): ConnectInfo<SocketAddr>, headers: HeaderMap| {
             let server = server.clone();
+            let forwarded = headers.get("x-forwarded-for").and_then(|value| value.to_str().ok());
+            let ip = server.client_addr(addr.ip(), forwarded);
             async move {
                 ws.on_upgrade(move |socket| async move {
-                    server.handle_socket(socket).await;
+                    server.handle_socket(socket, Some(ip)).await;
                 })
             }
         }))
         .layer(cors);
 
-    let port: u16 = std::env::var("PORT").unwrap_or("8080".into()).parse().unwrap();
     let addr = SocketAddr::from(([0, 0, 0, 0], port));
+    tracing::info!(%addr, "servidor no ar");
 
-    axum::serve(tokio::net::TcpListener::bind(addr).await.unwrap(), app)
+    axum::serve(
+        tokio::net::TcpListener::bind(addr).await.unwrap(),
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+        .with_graceful_shutdown(shutdown)
         .await
         .unwrap();
+
+    // o loop já viu a flag; no máximo um tick até terminar
+    let _ = tick_loop.await;
+    tracing::info!("servidor desligado");
 }
 
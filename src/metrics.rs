@@ -0,0 +1,175 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use sysinfo::{Pid, ProcessesToUpdate, System};
+
+use crate::entities::hitbox::EntityKind;
+
+/// Limites (segundos) do histograma de duração do tick. O orçamento a
+/// 32 ticks/s é 31,25 ms.
+pub const TICK_BUCKETS: [f64; 9] = [0.001, 0.0025, 0.005, 0.01, 0.015, 0.02, 0.03125, 0.05, 0.1];
+
+/// Rótulo `kind` do `asteroids_entities`, um por tipo de entidade.
+pub const ENTITY_KINDS: [(EntityKind, &str); 5] = [
+    (EntityKind::Player, "players"),
+    (EntityKind::Asteroid, "asteroids"),
+    (EntityKind::Bullet, "bullets"),
+    (EntityKind::PowerUp, "powerups"),
+    (EntityKind::Ufo, "ufos"),
+];
+
+static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::new);
+
+/// Contadores do servidor inteiro, lidos no `GET /metrics`.
+pub fn global() -> &'static Metrics {
+    &METRICS
+}
+
+/// Métricas no formato texto do Prometheus. Tudo atômico (ou mutex curto)
+/// pra poder registrar do loop de tick e dos sockets sem travar o jogo.
+pub struct Metrics {
+    // contagem por balde (não acumulada; acumula na hora de exportar)
+    tick_buckets: [AtomicU64; TICK_BUCKETS.len()],
+    tick_count: AtomicU64,
+    tick_sum_micros: AtomicU64,
+    tick_overruns: AtomicU64,
+
+    // por `EntityKind::rank`
+    entities: [AtomicU64; ENTITY_KINDS.len()],
+    clients: AtomicU64,
+
+    sent_bytes: AtomicU64,
+    tick_sent_bytes: AtomicU64,
+    messages: Mutex<BTreeMap<&'static str, u64>>,
+
+    // CPU precisa de duas leituras: a anterior fica guardada entre scrapes
+    process: Mutex<(System, Option<Pid>)>,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self {
+            tick_buckets: Default::default(),
+            tick_count: AtomicU64::new(0),
+            tick_sum_micros: AtomicU64::new(0),
+            tick_overruns: AtomicU64::new(0),
+            entities: Default::default(),
+            clients: AtomicU64::new(0),
+            sent_bytes: AtomicU64::new(0),
+            tick_sent_bytes: AtomicU64::new(0),
+            messages: Mutex::new(BTreeMap::new()),
+            process: Mutex::new((System::new(), sysinfo::get_current_pid().ok())),
+        }
+    }
+
+    /// Duração do tick; passou de `budget` conta como estouro.
+    pub fn record_tick(&self, elapsed: Duration, budget: Duration) {
+        let secs = elapsed.as_secs_f64();
+        if let Some(i) = TICK_BUCKETS.iter().position(|le| secs <= *le) {
+            self.tick_buckets[i].fetch_add(1, Ordering::Relaxed);
+        }
+        self.tick_count.fetch_add(1, Ordering::Relaxed);
+        self.tick_sum_micros.fetch_add(elapsed.as_micros() as u64, Ordering::Relaxed);
+
+        if elapsed > budget {
+            self.tick_overruns.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn set_entities(&self, kind: EntityKind, count: usize) {
+        self.entities[kind.rank() as usize].store(count as u64, Ordering::Relaxed);
+    }
+
+    pub fn set_clients(&self, clients: usize) {
+        self.clients.store(clients as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_sent_bytes(&self, bytes: usize) {
+        self.sent_bytes.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    /// Bytes do snapshot do último tick, somando todos os clientes.
+    pub fn set_tick_sent_bytes(&self, bytes: usize) {
+        self.tick_sent_bytes.store(bytes as u64, Ordering::Relaxed);
+    }
+
+    pub fn count_message(&self, kind: &'static str) {
+        if let Ok(mut messages) = self.messages.lock() {
+            *messages.entry(kind).or_insert(0) += 1;
+        }
+    }
+
+    /// Lê RAM/CPU do processo (chamada de sistema que bloqueia): no servidor
+    /// roda em `spawn_blocking`.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let load = |value: &AtomicU64| value.load(Ordering::Relaxed);
+
+        let _ = writeln!(out, "# HELP asteroids_tick_duration_seconds Tempo de processamento de um tick.");
+        let _ = writeln!(out, "# TYPE asteroids_tick_duration_seconds histogram");
+        let mut cumulative = 0;
+        for (le, bucket) in TICK_BUCKETS.iter().zip(self.tick_buckets.iter()) {
+            cumulative += load(bucket);
+            let _ = writeln!(out, "asteroids_tick_duration_seconds_bucket{{le=\"{}\"}} {}", le, cumulative);
+        }
+        let count = load(&self.tick_count);
+        let _ = writeln!(out, "asteroids_tick_duration_seconds_bucket{{le=\"+Inf\"}} {}", count);
+        let _ = writeln!(out, "asteroids_tick_duration_seconds_sum {}", load(&self.tick_sum_micros) as f64 / 1e6);
+        let _ = writeln!(out, "asteroids_tick_duration_seconds_count {}", count);
+
+        counter(&mut out, "asteroids_tick_overruns_total", "Ticks que passaram do orçamento.", load(&self.tick_overruns));
+
+        let _ = writeln!(out, "# HELP asteroids_entities Entidades vivas por tipo.");
+        let _ = writeln!(out, "# TYPE asteroids_entities gauge");
+        for (kind, label) in ENTITY_KINDS {
+            let _ = writeln!(out, "asteroids_entities{{kind=\"{}\"}} {}", label, load(&self.entities[kind.rank() as usize]));
+        }
+
+        gauge(&mut out, "asteroids_connected_clients", "Sessões WebSocket abertas.", load(&self.clients) as f64);
+        counter(&mut out, "asteroids_sent_bytes_total", "Bytes enviados aos clientes.", load(&self.sent_bytes));
+        gauge(
+            &mut out,
+            "asteroids_tick_sent_bytes",
+            "Bytes do último snapshot, somando todos os clientes.",
+            load(&self.tick_sent_bytes) as f64,
+        );
+
+        let _ = writeln!(out, "# HELP asteroids_messages_received_total Mensagens recebidas por tipo.");
+        let _ = writeln!(out, "# TYPE asteroids_messages_received_total counter");
+        if let Ok(messages) = self.messages.lock() {
+            for (kind, value) in messages.iter() {
+                let _ = writeln!(out, "asteroids_messages_received_total{{type=\"{}\"}} {}", kind, value);
+            }
+        }
+
+        if let Ok(mut process) = self.process.lock()
+            && let (system, Some(pid)) = &mut *process
+        {
+            let pid = *pid;
+            system.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+            if let Some(process) = system.process(pid) {
+                gauge(&mut out, "process_resident_memory_bytes", "RSS do processo.", process.memory() as f64);
+                gauge(&mut out, "process_cpu_usage_percent", "CPU desde o último scrape.", process.cpu_usage() as f64);
+            }
+        }
+
+        out
+    }
+}
+
+fn counter(out: &mut String, name: &str, help: &str, value: u64) {
+    let _ = writeln!(out, "# HELP {} {}\n# TYPE {} counter\n{} {}", name, help, name, name, value);
+}
+
+fn gauge(out: &mut String, name: &str, help: &str, value: f64) {
+    let _ = writeln!(out, "# HELP {} {}\n# TYPE {} gauge\n{} {}", name, help, name, name, value);
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+use crate::collections::player_collection::PlayerCollection;
+use crate::config::GameConfig;
+use crate::entities::player::Player;
+use crate::entities::traits::warp_object::WarpObject;
+use crate::events::{DeathCause, GameEvent};
+use crate::modes::{GameMode, RoundOutcome};
+use crate::types::ClientId;
+use crate::world::components::{Transform, Velocity};
+
+/// Área segura circular. Fora dela a nave tem um tempo limite pra voltar.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SafeZone {
+    pub x: f32,
+    pub y: f32,
+    pub radius: f32,
+}
+
+impl SafeZone {
+    pub fn contains(&self, (x, y): (f32, f32)) -> bool {
+        let dx = x - self.x;
+        let dy = y - self.y;
+        dx * dx + dy * dy <= self.radius * self.radius
+    }
+
+    /// Segura no limite quem acabou de tentar sair (estava dentro antes do
+    /// movimento) e tira a parte da velocidade que aponta pra fora. Quem
+    /// ficou fora quando a zona encolheu anda livre pra voltar.
+    pub fn confine(&self, transform: &mut Transform, velocity: &mut Velocity) {
+        let before = (transform.x - velocity.vx, transform.y - velocity.vy);
+        if self.contains(transform.position()) || !self.contains(before) {
+            return;
+        }
+
+        let (dx, dy) = (transform.x - self.x, transform.y - self.y);
+        let dist = (dx * dx + dy * dy).sqrt();
+        let (nx, ny) = (dx / dist, dy / dist);
+        // um fio pra dentro, pra não escapar por arredondamento
+        let radius = self.radius - 0.01;
+        transform.x = self.x + nx * radius;
+        transform.y = self.y + ny * radius;
+
+        let outward = velocity.vx * nx + velocity.vy * ny;
+        if outward > 0.0 {
+            velocity.vx -= outward * nx;
+            velocity.vy -= outward * ny;
+        }
+    }
+}
+
+/// Último sobrevivente vence. O mundo não dá a volta nas bordas, nada sai
+/// da zona por conta própria e ela encolhe em degraus até `min_radius`.
+#[derive(Clone)]
+pub struct BattleRoyale {
+    tick_rate: u8,
+    zone: SafeZone,
+    start_radius: f32,
+    min_radius: f32,
+    shrink_factor: f32,
+    shrink_interval: u32,
+    next_shrink: u32,
+    outside_limit: u32,
+    // ticks seguidos fora da zona, por nave viva
+    outside: HashMap<ClientId, u32>,
+    // quantos entraram nesta rodada e não saíram do jogo (precisa de 2 pra
+    // ter vencedor); quem morre continua contando
+    entrants: usize,
+}
+
+impl BattleRoyale {
+    /// Intervalo de encolhimento e tolerância fora da zona vêm de
+    /// `config.mode` (`zone_shrink_secs`, `zone_grace_secs`).
+    pub fn new(config: &GameConfig) -> Self {
+        let world = config.world_size as f32;
+        // começa cobrindo o mundo inteiro (raio = meia diagonal)
+        let start_radius = world * std::f32::consts::FRAC_1_SQRT_2;
+        let shrink_interval = config.ticks(config.mode.zone_shrink_secs).max(1);
+
+        Self {
+            tick_rate: config.tick_rate,
+            zone: SafeZone { x: world / 2.0, y: world / 2.0, radius: start_radius },
+            start_radius,
+            min_radius: 300.0,
+            shrink_factor: 0.75,
+            shrink_interval,
+            next_shrink: shrink_interval,
+            outside_limit: config.ticks(config.mode.zone_grace_secs),
+            outside: HashMap::new(),
+            entrants: 0,
+        }
+    }
+
+    pub fn zone(&self) -> &SafeZone {
+        &self.zone
+    }
+}
+
+impl Default for BattleRoyale {
+    fn default() -> Self {
+        Self::new(&GameConfig::default())
+    }
+}
+
+impl GameMode for BattleRoyale {
+    fn name(&self) -> &'static str {
+        "battle_royale"
+    }
+
+    fn wraps_world(&self) -> bool {
+        false
+    }
+
+    fn respawns(&self) -> bool {
+        false
+    }
+
+    fn boundary(&self) -> Option<&SafeZone> {
+        Some(&self.zone)
+    }
+
+    fn on_join(&mut self, _player: &mut Player) {
+        self.entrants += 1;
+    }
+
+    fn on_leave(&mut self, id: &ClientId) {
+        self.entrants = self.entrants.saturating_sub(1);
+        self.outside.remove(id);
+    }
+
+    fn on_tick(&mut self, players: &mut PlayerCollection, events: &mut Vec<GameEvent>) {
+        self.next_shrink = self.next_shrink.saturating_sub(1);
+        if self.next_shrink == 0 {
+            self.zone.radius = (self.zone.radius * self.shrink_factor).max(self.min_radius);
+            self.next_shrink = self.shrink_interval;
+        }
+
+        // morto por tiro ou asteroide não leva a contagem pra próxima vida
+        self.outside.retain(|id, _| players.is_alive(id));
+
+        let mut expired = Vec::new();
+        for player in players.iter().filter(|p| !p.is_destroyed()) {
+            let id = player.get_id();
+            if self.zone.contains(player.position()) {
+                self.outside.remove(&id);
+                continue;
+            }
+
+            let ticks = self.outside.entry(id).or_insert(0);
+            *ticks += 1;
+            if *ticks >= self.outside_limit {
+                expired.push(id);
+            }
+        }
+
+        for id in expired {
+            self.outside.remove(&id);
+            // a zona não mata sozinha: o crédito vai pra quem bateu por último
+            let killer = players.get_player(&id).and_then(|p| p.last_attacker());
+            if players.kill(&id) {
+                events.push(GameEvent::PlayerKilled { victim: id, killer, cause: DeathCause::Zone });
+            }
+        }
+    }
+
+    fn check_round_end(&self, players: &PlayerCollection) -> Option<RoundOutcome> {
+        if self.entrants < 2 || players.len() > 1 {
+            return None;
+        }
+        Some(RoundOutcome { winner: players.iter().next().map(|p| p.get_id()) })
+    }
+
+    fn on_round_end(&mut self, players: &mut PlayerCollection) {
+        self.zone.radius = self.start_radius;
+        self.next_shrink = self.shrink_interval;
+        self.outside.clear();
+        // quem sobrou é quem entrou na próxima rodada
+        self.entrants = players.len();
+        players.reset_scores();
+    }
+
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"name\": \"{}\", \"zone\": {{\"x\": {}, \"y\": {}, \"radius\": {}, \"next_shrink\": {} }} }}",
+            self.name(),
+            self.zone.x,
+            self.zone.y,
+            self.zone.radius,
+            self.next_shrink as f32 / self.tick_rate as f32
+        )
+    }
+
+    fn box_clone(&self) -> Box<dyn GameMode> {
+        Box::new(self.clone())
+    }
+}
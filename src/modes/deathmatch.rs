@@ -0,0 +1,41 @@
+use crate::collections::player_collection::PlayerCollection;
+use crate::config::GameConfig;
+use crate::modes::{GameMode, RoundOutcome};
+use crate::types::ClientId;
+
+pub const KILL_POINTS: u32 = 500;
+
+/// Todos contra todos: vence quem chegar primeiro em `score_limit`.
+#[derive(Clone)]
+pub struct Deathmatch {
+    pub score_limit: u32,
+}
+
+impl Default for Deathmatch {
+    fn default() -> Self {
+        Self { score_limit: GameConfig::default().mode.score_limit }
+    }
+}
+
+impl GameMode for Deathmatch {
+    fn name(&self) -> &'static str {
+        "deathmatch"
+    }
+
+    fn on_kill(&mut self, players: &mut PlayerCollection, _victim: &ClientId, killer: Option<&ClientId>) {
+        if let Some(id) = killer {
+            players.add_score(id, KILL_POINTS);
+        }
+    }
+
+    fn check_round_end(&self, players: &PlayerCollection) -> Option<RoundOutcome> {
+        players
+            .leader()
+            .filter(|p| p.score() >= self.score_limit)
+            .map(|p| RoundOutcome { winner: Some(p.get_id()) })
+    }
+
+    fn box_clone(&self) -> Box<dyn GameMode> {
+        Box::new(self.clone())
+    }
+}
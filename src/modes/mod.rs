@@ -0,0 +1,119 @@
+pub mod battle_royale;
+pub mod deathmatch;
+pub mod survival;
+pub mod time_attack;
+
+use crate::collections::player_collection::PlayerCollection;
+use crate::config::GameConfig;
+use crate::collections::team_collection::TeamCollection;
+use crate::entities::asteroid::AsteroidType;
+use crate::entities::player::Player;
+use crate::events::GameEvent;
+use crate::types::ClientId;
+
+use battle_royale::{BattleRoyale, SafeZone};
+use deathmatch::Deathmatch;
+use survival::Survival;
+use time_attack::TimeAttack;
+
+/// Fim de rodada decidido pelo modo. `winner` vazio = ninguém venceu
+/// (ex.: todos morreram no co-op).
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoundOutcome {
+    pub winner: Option<ClientId>,
+}
+
+/// Regras de uma sala. O `GameManager` chama os hooks a partir dos eventos
+/// do tick; o modo só mexe em pontuação e decide quando a rodada acaba.
+pub trait GameMode: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn on_join(&mut self, _player: &mut Player) {}
+
+    /// Saiu da sala ainda vivo (quem morre sem volta já saiu antes).
+    fn on_leave(&mut self, _id: &ClientId) {}
+
+    fn on_kill(&mut self, _players: &mut PlayerCollection, _victim: &ClientId, _killer: Option<&ClientId>) {}
+
+    fn on_asteroid_destroyed(&mut self, players: &mut PlayerCollection, tier: AsteroidType, by: Option<&ClientId>) {
+        if let Some(id) = by {
+            players.add_score(id, asteroid_points(tier));
+        }
+    }
+
+    fn on_ufo_destroyed(&mut self, players: &mut PlayerCollection, by: Option<&ClientId>) {
+        if let Some(id) = by {
+            players.add_score(id, UFO_POINTS);
+        }
+    }
+
+    /// Times da sala quando ela é criada com esse modo.
+    fn default_teams(&self) -> TeamCollection {
+        TeamCollection::none()
+    }
+
+    /// Se naves e entidades dão a volta nas bordas do mundo.
+    fn wraps_world(&self) -> bool {
+        true
+    }
+
+    /// Zona que segura tudo o que está dentro dela.
+    fn boundary(&self) -> Option<&SafeZone> {
+        None
+    }
+
+    /// Se a nave destruída volta depois de `ship.respawn_secs`; sem volta,
+    /// morrer tira do jogo até entrar de novo.
+    fn respawns(&self) -> bool {
+        true
+    }
+
+    /// Chamado uma vez por tick, depois da simulação. Mortes causadas
+    /// pelo próprio modo saem como eventos.
+    fn on_tick(&mut self, _players: &mut PlayerCollection, _events: &mut Vec<GameEvent>) {}
+
+    /// Condição de vitória; `Some` encerra a rodada.
+    fn check_round_end(&self, players: &PlayerCollection) -> Option<RoundOutcome>;
+
+    /// Zera o estado da rodada. Por padrão só zera a pontuação.
+    fn on_round_end(&mut self, players: &mut PlayerCollection) {
+        players.reset_scores();
+    }
+
+    /// Estado do modo que vai no snapshot (campo `Mode`).
+    fn to_json(&self) -> String {
+        format!("{{\"name\": \"{}\"}}", self.name())
+    }
+
+    fn box_clone(&self) -> Box<dyn GameMode>;
+}
+
+impl Clone for Box<dyn GameMode> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
+}
+
+/// Pontuação clássica: quanto menor o asteroide, mais pontos.
+pub fn asteroid_points(tier: AsteroidType) -> u32 {
+    match tier {
+        AsteroidType::BIG => 20,
+        AsteroidType::MEDIUM => 50,
+        AsteroidType::SMALL => 100,
+    }
+}
+
+/// Disco voador derrubado vale como o disco grande do arcade.
+pub const UFO_POINTS: u32 = 200;
+
+/// Cria o modo de `config.mode.name` (`deathmatch`, `survival`,
+/// `time_attack`, `battle_royale`). `None` se o nome não existe.
+pub fn from_config(config: &GameConfig) -> Option<Box<dyn GameMode>> {
+    match config.mode.name.as_str() {
+        "deathmatch" => Some(Box::new(Deathmatch { score_limit: config.mode.score_limit })),
+        "survival" => Some(Box::new(Survival::default())),
+        "time_attack" => Some(Box::new(TimeAttack::new(config))),
+        "battle_royale" => Some(Box::new(BattleRoyale::new(config))),
+        _ => None,
+    }
+}
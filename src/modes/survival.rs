@@ -0,0 +1,46 @@
+use crate::collections::player_collection::PlayerCollection;
+use crate::collections::team_collection::TeamCollection;
+use crate::entities::player::Player;
+use crate::modes::{GameMode, RoundOutcome};
+
+/// Co-op contra os asteroides: todos no mesmo time, sem fogo amigo, e a
+/// rodada acaba quando não sobra nenhuma nave.
+#[derive(Clone, Default)]
+pub struct Survival {
+    // só encerra depois que alguém jogou a rodada
+    has_players: bool,
+}
+
+impl GameMode for Survival {
+    fn name(&self) -> &'static str {
+        "survival"
+    }
+
+    fn on_join(&mut self, _player: &mut Player) {
+        self.has_players = true;
+    }
+
+    fn respawns(&self) -> bool {
+        false
+    }
+
+    fn default_teams(&self) -> TeamCollection {
+        TeamCollection::new(1, false)
+    }
+
+    fn check_round_end(&self, players: &PlayerCollection) -> Option<RoundOutcome> {
+        if self.has_players && players.is_empty() {
+            return Some(RoundOutcome { winner: None });
+        }
+        None
+    }
+
+    fn on_round_end(&mut self, players: &mut PlayerCollection) {
+        self.has_players = false;
+        players.reset_scores();
+    }
+
+    fn box_clone(&self) -> Box<dyn GameMode> {
+        Box::new(self.clone())
+    }
+}
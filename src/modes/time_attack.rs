@@ -0,0 +1,61 @@
+use crate::collections::player_collection::PlayerCollection;
+use crate::events::GameEvent;
+use crate::config::GameConfig;
+use crate::modes::{GameMode, RoundOutcome};
+
+/// Rodada de duração fixa: ao fim do tempo vence a maior pontuação.
+#[derive(Clone)]
+pub struct TimeAttack {
+    tick_rate: u8,
+    duration: u32,
+    remaining: u32,
+}
+
+impl TimeAttack {
+    /// Duração em `config.mode.time_limit_secs`.
+    pub fn new(config: &GameConfig) -> Self {
+        let duration = config.ticks(config.mode.time_limit_secs as f32);
+        Self { tick_rate: config.tick_rate, duration, remaining: duration }
+    }
+
+    /// Segundos restantes na rodada.
+    pub fn remaining_secs(&self) -> f32 {
+        self.remaining as f32 / self.tick_rate as f32
+    }
+}
+
+impl Default for TimeAttack {
+    fn default() -> Self {
+        Self::new(&GameConfig::default())
+    }
+}
+
+impl GameMode for TimeAttack {
+    fn name(&self) -> &'static str {
+        "time_attack"
+    }
+
+    fn on_tick(&mut self, _players: &mut PlayerCollection, _events: &mut Vec<GameEvent>) {
+        self.remaining = self.remaining.saturating_sub(1);
+    }
+
+    fn check_round_end(&self, players: &PlayerCollection) -> Option<RoundOutcome> {
+        if self.remaining > 0 {
+            return None;
+        }
+        Some(RoundOutcome { winner: players.leader().map(|p| p.get_id()) })
+    }
+
+    fn on_round_end(&mut self, players: &mut PlayerCollection) {
+        self.remaining = self.duration;
+        players.reset_scores();
+    }
+
+    fn to_json(&self) -> String {
+        format!("{{\"name\": \"{}\", \"remaining\": {} }}", self.name(), self.remaining_secs())
+    }
+
+    fn box_clone(&self) -> Box<dyn GameMode> {
+        Box::new(self.clone())
+    }
+}
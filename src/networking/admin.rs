@@ -0,0 +1,245 @@
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use axum::body::to_bytes;
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::Json;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tokio::fs::OpenOptions;
+use tokio::io::AsyncWriteExt;
+
+use crate::config::{AdminConfig, GameConfig};
+use crate::entities::asteroid::AsteroidType;
+use crate::entities::traits::warp_object::WarpObject;
+use crate::networking::websocket_handler::WebSocketHandler;
+use crate::types::ClientId;
+
+// corpo de comando é pequeno; o resto é abuso
+const MAX_BODY: usize = 16 * 1024;
+
+/// Comandos do canal de admin (`POST /admin`), no mesmo formato das
+/// mensagens do cliente: `{"action": "kick", "id": "..."}`.
+#[derive(Deserialize, Debug)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum AdminCommand {
+    ListPlayers,
+    Kick {
+        id: ClientId,
+    },
+    /// Por `id` bane o IP de quem está naquela sessão.
+    Ban {
+        id: Option<ClientId>,
+        ip: Option<IpAddr>,
+    },
+    Unban {
+        ip: IpAddr,
+    },
+    Pause,
+    Resume,
+    SpawnAsteroid {
+        x: f32,
+        y: f32,
+        tier: AsteroidType,
+    },
+    ClearField,
+    Notice {
+        message: String,
+    },
+    ReloadConfig,
+}
+
+/// Operação do servidor ao vivo. Só responde com o segredo de
+/// `config.admin.secret`, e toda tentativa vai pro log de auditoria.
+pub struct Admin {
+    server: Arc<WebSocketHandler>,
+    secret: String,
+    audit_log: String,
+}
+
+impl Admin {
+    pub fn new(server: Arc<WebSocketHandler>, config: &AdminConfig) -> Self {
+        Self {
+            server,
+            secret: config.secret.clone(),
+            audit_log: config.audit_log.clone(),
+        }
+    }
+
+    /// Segredo vazio desliga o canal: ninguém passa.
+    pub fn authorize(&self, token: Option<&str>) -> bool {
+        match token {
+            Some(token) => !self.secret.is_empty() && constant_time_eq(token.as_bytes(), self.secret.as_bytes()),
+            None => false,
+        }
+    }
+
+    pub async fn execute(&self, command: &AdminCommand) -> Result<Value, String> {
+        match command {
+            AdminCommand::ListPlayers => Ok(self.list_players().await),
+
+            AdminCommand::Kick { id } => match self.server.kick(id).await {
+                true => Ok(json!({ "kicked": id })),
+                false => Err(format!("sessão {} não existe", id)),
+            },
+
+            AdminCommand::Ban { id, ip } => {
+                let ip = match (ip, id) {
+                    (Some(ip), _) => *ip,
+                    (None, Some(id)) => self
+                        .server
+                        .client_ip(id)
+                        .await
+                        .ok_or_else(|| format!("sessão {} não existe ou não tem IP", id))?,
+                    (None, None) => return Err("informe id ou ip".to_string()),
+                };
+
+                self.server.ban(ip).await;
+                let kicked = self.server.kick_ip(&ip).await;
+                Ok(json!({ "banned": ip, "kicked": kicked }))
+            }
+
+            AdminCommand::Unban { ip } => match self.server.unban(ip).await {
+                true => Ok(json!({ "unbanned": ip })),
+                false => Err(format!("{} não estava banido", ip)),
+            },
+
+            AdminCommand::Pause => {
+                self.server.router().game().lock().await.pause();
+                Ok(json!({ "paused": true }))
+            }
+
+            AdminCommand::Resume => {
+                self.server.router().game().lock().await.resume();
+                Ok(json!({ "paused": false }))
+            }
+
+            AdminCommand::SpawnAsteroid { x, y, tier } => {
+                match self.server.router().game().lock().await.spawn_asteroid(*x, *y, *tier) {
+                    true => Ok(json!({ "spawned": tier })),
+                    false => Err("campo cheio".to_string()),
+                }
+            }
+
+            AdminCommand::ClearField => {
+                self.server.router().game().lock().await.clear_field();
+                Ok(json!({ "cleared": true }))
+            }
+
+            AdminCommand::Notice { message } => {
+                let notice = json!({ "type": "server_notice", "message": message });
+                self.server.broadcast(notice.to_string()).await;
+                Ok(json!({ "sent": true }))
+            }
+
+            AdminCommand::ReloadConfig => {
+                let config = GameConfig::load()?;
+                let changed = self.server.router().reload_config(config).await?;
+                Ok(json!({ "changed": changed }))
+            }
+        }
+    }
+
+    async fn list_players(&self) -> Value {
+        let clients = self.server.get_clients().await;
+        let game = self.server.router().game().lock().await;
+
+        let sessions: Vec<Value> = clients
+            .iter()
+            .map(|client| {
+                let player = game.players.get_player(&client.id);
+                json!({
+                    "id": client.id,
+                    "ip": client.ip,
+                    "in_game": player.is_some(),
+                    "score": player.as_ref().map(|p| p.score()),
+                    "team": player.as_ref().and_then(|p| p.team()),
+                    "position": player.as_ref().map(|p| p.position()),
+                })
+            })
+            .collect();
+
+        json!({ "sessions": sessions, "paused": game.is_paused() })
+    }
+
+    /// Uma linha por ação: quando, de onde, o quê e no que deu.
+    pub async fn audit(&self, source: &str, action: &str, outcome: &Result<Value, String>) {
+        let when = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        let outcome = match outcome {
+            Ok(_) => "ok".to_string(),
+            Err(e) => format!("erro: {}", e.replace('\n', "; ")),
+        };
+        tracing::info!(target: "audit", source, action, outcome = %outcome, "admin");
+
+        if self.audit_log.is_empty() {
+            return;
+        }
+
+        let line = format!("{} admin {} {} -> {}\n", when, source, action, outcome);
+        let written = match OpenOptions::new().create(true).append(true).open(&self.audit_log).await {
+            Ok(mut file) => file.write_all(line.as_bytes()).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = written {
+            tracing::error!(path = %self.audit_log, error = %e, "não consegui gravar o log de auditoria");
+        }
+    }
+}
+
+/// Compara sem sair no primeiro byte diferente, pra o tempo de resposta
+/// não entregar quanto do segredo já acertaram.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    let diff = (0..a.len().max(b.len())).fold(a.len() ^ b.len(), |acc, i| {
+        acc | (a.get(i).copied().unwrap_or(0) ^ b.get(i).copied().unwrap_or(0)) as usize
+    });
+    diff == 0
+}
+
+/// Rotas do canal de admin. Monte só se houver segredo configurado.
+pub fn routes(admin: Arc<Admin>) -> axum::Router {
+    axum::Router::new().route("/admin", post(handle_admin)).with_state(admin)
+}
+
+async fn handle_admin(State(admin): State<Arc<Admin>>, request: Request) -> Response {
+    let forwarded = request.headers().get("x-forwarded-for").and_then(|value| value.to_str().ok());
+    let source = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| admin.server.client_addr(addr.ip(), forwarded).to_string())
+        .unwrap_or_else(|| "?".to_string());
+
+    let token = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    if !admin.authorize(token) {
+        admin.audit(&source, "auth", &Err("segredo inválido".to_string())).await;
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    let command = match to_bytes(request.into_body(), MAX_BODY).await {
+        Ok(body) => serde_json::from_slice::<AdminCommand>(&body).map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+    let command = match command {
+        Ok(command) => command,
+        Err(e) => {
+            admin.audit(&source, "parse", &Err(e.clone())).await;
+            return (StatusCode::BAD_REQUEST, Json(json!({ "error": e }))).into_response();
+        }
+    };
+
+    let outcome = admin.execute(&command).await;
+    admin.audit(&source, &format!("{:?}", command), &outcome).await;
+
+    match outcome {
+        Ok(body) => (StatusCode::OK, Json(body)).into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, Json(json!({ "error": e }))).into_response(),
+    }
+}
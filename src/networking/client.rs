@@ -3,8 +3,10 @@ use futures_util::stream::SplitSink;
 use axum::extract::ws::Message;
 use uuid::Uuid;
 
+use std::net::IpAddr;
 use std::sync::Arc;
 use futures_util::lock::Mutex;
+use tokio::sync::Notify;
 
 use crate::types::ClientId;
 
@@ -12,13 +14,21 @@ use crate::types::ClientId;
 pub struct Client {
     pub id: ClientId,
     pub sender: Arc<Mutex<SplitSink<WebSocket, Message>>>,
+    // endereço de origem, pra ban por IP (None quando o servidor não sabe)
+    pub ip: Option<IpAddr>,
+    // derrubado pelo servidor: o loop de leitura do socket para
+    pub closed: Arc<Notify>,
 }
 
 impl Client {
-    pub fn new(sender: SplitSink<WebSocket, Message>) -> Self {
+    /// Id aleatório de verdade: é entrada externa da simulação (o replay
+    /// grava os ids de quem entrou), não precisa sair da seed.
+    pub fn new(sender: SplitSink<WebSocket, Message>, ip: Option<IpAddr>) -> Self {
         Self {
             id: Uuid::new_v4(),
             sender: Arc::new(Mutex::new(sender)),
+            ip,
+            closed: Arc::new(Notify::new()),
         }
     }
 }
@@ -1,3 +1,4 @@
 pub mod client;
 pub mod websocket_handler;
-pub mod router;
\ No newline at end of file
+pub mod router;
+pub mod admin;
\ No newline at end of file
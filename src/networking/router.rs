@@ -3,7 +3,9 @@ use tokio::time::{Instant, Duration};
 
 use std::sync::Arc;
 
-use crate::types::{ClientId, TICK_RATE, WORLD_SIZE};
+use crate::config::{GameConfig, ShipConfig, WeaponsConfig};
+use crate::entities::weapon::WeaponKind;
+use crate::types::{ClientId, TeamId};
 use crate::game::{GameManager};
 
 use serde::{Deserialize, Serialize};
@@ -16,7 +18,7 @@ pub struct MovePayload {
     pub fire: bool,
 }
 
-/// Pacote com as constantes do jogo enviadas ao frontend.
+/// Pacote com os valores efetivos da config enviados ao frontend.
 /// O campo `type` permite ao cliente distinguir de outras mensagens.
 #[derive(Serialize)]
 pub struct GameInfo {
@@ -24,30 +26,71 @@ pub struct GameInfo {
     msg_type: &'static str,
     tick_rate: u8,
     world_size: u32,
+    mode: String,
+    max_players: usize,
+    max_asteroids: usize,
+    max_bullets: usize,
+    teams: usize,
+    friendly_fire: bool,
+    ship: ShipConfig,
+    weapons: WeaponsConfig,
 }
 
 impl GameInfo {
-    pub fn current() -> Self {
+    pub fn from_config(config: &GameConfig) -> Self {
         Self {
             msg_type: "game_info",
-            tick_rate: TICK_RATE,
-            world_size: WORLD_SIZE,
+            tick_rate: config.tick_rate,
+            world_size: config.world_size,
+            mode: config.mode.name.clone(),
+            max_players: config.max_players,
+            max_asteroids: config.max_asteroids,
+            max_bullets: config.max_bullets,
+            teams: config.teams,
+            friendly_fire: config.friendly_fire,
+            ship: config.ship.clone(),
+            weapons: config.weapons.clone(),
         }
     }
 }
 
 
+/// Mensagens do cliente. A sessão abre junto com o socket; `Connect` e
+/// `Disconnect` só entram e saem do jogo dentro dela.
 #[derive(Deserialize)]
 #[serde(tag = "action", rename_all = "snake_case")]
 pub enum ClientMessage {
+    Connect,
+    Disconnect,
     Move {
         #[serde(flatten)]
         data: MovePayload,
     },
+    ChooseTeam {
+        team: TeamId,
+    },
+    ChooseWeapon {
+        weapon: WeaponKind,
+    },
     GetGameInfo,
     Ping,
 }
 
+impl ClientMessage {
+    /// Mesmo nome do campo `action`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ClientMessage::Connect => "connect",
+            ClientMessage::Disconnect => "disconnect",
+            ClientMessage::Move { .. } => "move",
+            ClientMessage::ChooseTeam { .. } => "choose_team",
+            ClientMessage::ChooseWeapon { .. } => "choose_weapon",
+            ClientMessage::GetGameInfo => "get_game_info",
+            ClientMessage::Ping => "ping",
+        }
+    }
+}
+
 
 pub enum WsResponse {
     Broadcast(String),
@@ -68,43 +111,103 @@ impl Router {
         }
     }
 
+    /// Acesso direto ao jogo, pro canal de admin.
+    pub fn game(&self) -> &Arc<Mutex<GameManager>> {
+        &self.game
+    }
+
     pub async fn handle_message(&self, client_id: &ClientId, message: &ClientMessage) -> WsResponse {
         match message {
+            ClientMessage::Connect => self.handle_join(client_id).await,
+
+            ClientMessage::Disconnect => self.handle_leave(client_id).await,
+
             ClientMessage::Move{data} => {
                 self.game.lock().await.handle_player_command(client_id, data);
                 WsResponse::Nothing
             }
 
+            ClientMessage::ChooseTeam{team} => {
+                match self.game.lock().await.choose_team(client_id, *team) {
+                    Ok(_) => WsResponse::Nothing,
+                    Err(msg) => WsResponse::Error(msg.to_string()),
+                }
+            }
+
+            ClientMessage::ChooseWeapon{weapon} => {
+                match self.game.lock().await.choose_weapon(client_id, *weapon) {
+                    Ok(_) => WsResponse::Nothing,
+                    Err(msg) => WsResponse::Error(msg.to_string()),
+                }
+            }
+
             ClientMessage::GetGameInfo => {
-                let info = serde_json::to_string(&GameInfo::current()).unwrap_or_default();
-                WsResponse::Unicast(client_id.clone(), info)
+                let info = GameInfo::from_config(self.game.lock().await.config());
+                let info = serde_json::to_string(&info).unwrap_or_default();
+                WsResponse::Unicast(*client_id, info)
             }
 
             ClientMessage::Ping => {
-                WsResponse::Unicast(client_id.clone(), "pong".to_string())
+                WsResponse::Unicast(*client_id, "pong".to_string())
             }
         }
     }
 
 
-    pub async fn handle_connect(&self, client_id: &ClientId) {
-        let _ = self.game.lock().await.players.add_player(client_id);
+    /// Entra no jogo (nova nave) sem abrir outra sessão.
+    pub async fn handle_join(&self, client_id: &ClientId) -> WsResponse {
+        match self.game.lock().await.add_player(client_id) {
+            Ok(id) => WsResponse::Unicast(id, format!("connected:{}", id)),
+            Err(msg) => WsResponse::Error(msg.to_string()),
+        }
+    }
+
+    /// Sai do jogo mas mantém a sessão (o socket continua aberto).
+    pub async fn handle_leave(&self, client_id: &ClientId) -> WsResponse {
+        self.game.lock().await.remove_player(client_id);
+        WsResponse::Unicast(*client_id, "disconnected".to_string())
     }
 
+    /// Socket fechou: sessão acabou, tira a nave se ainda estiver no jogo.
     pub async fn handle_disconnect(&self, client_id: &ClientId) {
-        self.game.lock().await.players.rm_player(client_id);
+        self.game.lock().await.remove_player(client_id);
+    }
+
+    /// Config recarregada (arquivo ou admin): entra no próximo tick.
+    pub async fn reload_config(&self, config: GameConfig) -> Result<Vec<String>, String> {
+        self.game.lock().await.reload_config(config)
     }
 
     pub async fn game_tick(&self,) -> String {
-        let tick_duration = Duration::from_secs_f64(1.0 / TICK_RATE as f64);
         let t0 = Instant::now();
+        let tick_rate = self.game.lock().await.config().tick_rate;
+        let tick_duration = Duration::from_secs_f64(1.0 / tick_rate as f64);
+
+        let game_state = {
+            let mut game = self.game.lock().await;
+            game.tick();
+
+            #[cfg(feature = "metrics")]
+            for (kind, _) in crate::metrics::ENTITY_KINDS {
+                let count = match kind {
+                    crate::entities::hitbox::EntityKind::Player => game.players.len(),
+                    kind => game.world.count(kind),
+                };
+                crate::metrics::global().set_entities(kind, count);
+            }
+
+            game.get_game_state()
+        };
 
-        self.game.lock().await.tick();
-        let game_state = self.game.lock().await.get_game_state();
-        //println!("{}", self.game.lock().await.game_info());
-        
         let dt = Instant::now() - t0;
-        tokio::time::sleep(tick_duration - dt).await;
+        if dt > tick_duration {
+            tracing::warn!(elapsed_ms = dt.as_secs_f64() * 1000.0, "tick passou do orçamento");
+        }
+        #[cfg(feature = "metrics")]
+        crate::metrics::global().record_tick(dt, tick_duration);
+
+        // tick atrasado não dorme (e não pode subtrair pra negativo)
+        tokio::time::sleep(tick_duration.saturating_sub(dt)).await;
         game_state
     }
 }
\ No newline at end of file
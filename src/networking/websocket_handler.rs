@@ -1,11 +1,20 @@
-use axum::extract::ws::{Message, WebSocket};
+use axum::extract::ws::{Message, Utf8Bytes, WebSocket};
 use futures_util::stream::SplitSink;
+use futures_util::future::join_all;
 use futures_util::{SinkExt, StreamExt};
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, Notify};
+use tracing::Instrument;
 
 
-use std::{collections::HashMap, sync::Arc};
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::time::Instant;
 
+use crate::collections::asteroid_collection::AsteroidCollection;
+use crate::config::HeartbeatConfig;
 use crate::networking::router::{Router, ClientMessage, WsResponse};
 use crate::types::{ClientId};
 use crate::networking::client::Client;
@@ -15,98 +24,330 @@ type ClientMap = Arc<Mutex<HashMap<ClientId, Client>>>;
 #[derive(Clone)]
 pub struct WebSocketHandler {
     clients: ClientMap,
+    // IPs banidos pelo admin: o socket fecha logo ao abrir
+    banned: Arc<Mutex<HashSet<IpAddr>>>,
+    // desligando: o loop de tick para e socket novo é recusado
+    shutting_down: Arc<AtomicBool>,
+    // proxies confiáveis na frente (`proxy.trusted_hops`)
+    trusted_hops: usize,
+    // escrita no socket que passa disso derruba o cliente
+    send_timeout: Duration,
     router: Router
 }
 
+/// Endereço de quem está na ponta. Com `hops` proxies confiáveis na
+/// frente, é a entrada do `X-Forwarded-For` que o mais distante deles
+/// anotou; o que vem antes disso o próprio cliente pode ter inventado.
+pub fn forwarded_ip(peer: IpAddr, forwarded: Option<&str>, hops: usize) -> IpAddr {
+    if hops == 0 {
+        return peer;
+    }
+
+    let entries: Vec<&str> = forwarded.unwrap_or_default().split(',').map(str::trim).collect();
+    entries
+        .len()
+        .checked_sub(hops)
+        .and_then(|i| entries[i].parse().ok())
+        .unwrap_or(peer)
+}
+
 impl WebSocketHandler {
     pub fn new(router: Router) -> Self {
         Self {
             clients: Arc::new(Mutex::new(HashMap::new())),
+            banned: Arc::new(Mutex::new(HashSet::new())),
+            shutting_down: Arc::new(AtomicBool::new(false)),
+            trusted_hops: 0,
+            send_timeout: Duration::from_secs_f32(HeartbeatConfig::default().timeout_secs),
             router
         }
     }
 
+    pub fn with_trusted_proxies(mut self, hops: usize) -> Self {
+        self.trusted_hops = hops;
+        self
+    }
+
+    /// Normalmente o `heartbeat.timeout_secs` da partida.
+    pub fn with_send_timeout(mut self, timeout: Duration) -> Self {
+        self.send_timeout = timeout;
+        self
+    }
+
+    /// `forwarded_ip` com os proxies configurados.
+    pub fn client_addr(&self, peer: IpAddr, forwarded: Option<&str>) -> IpAddr {
+        forwarded_ip(peer, forwarded, self.trusted_hops)
+    }
+
+    pub fn router(&self) -> &Router {
+        &self.router
+    }
+
     pub async fn get_clients(&self) -> Vec<Client> {
         self.clients.lock().await.values().cloned().collect()
     }
 
-    pub async  fn on_connect(&self, sender: SplitSink<WebSocket, Message>) -> ClientId {
-        let client = Client::new(sender);
+    pub async fn client_ip(&self, client_id: &ClientId) -> Option<IpAddr> {
+        self.clients.lock().await.get(client_id).and_then(|c| c.ip)
+    }
+
+    pub async fn is_banned(&self, ip: &IpAddr) -> bool {
+        self.banned.lock().await.contains(ip)
+    }
 
-        self.clients.lock().await.insert(client.id, client.clone());
-        println!("Cliente {} conectado", client.id);
-        self.router.handle_connect(&client.id).await;
-        self.unicast(&client.id, format!("connected:{}", client.id)).await;
+    pub async fn ban(&self, ip: IpAddr) {
+        self.banned.lock().await.insert(ip);
+    }
 
-        client.id
+    pub async fn unban(&self, ip: &IpAddr) -> bool {
+        self.banned.lock().await.remove(ip)
+    }
+
+    /// Derruba a sessão: tira a nave, fecha o socket e para de ler o que
+    /// ainda chegar dele.
+    pub async fn kick(&self, client_id: &ClientId) -> bool {
+        let Some(client) = self.clients.lock().await.remove(client_id) else {
+            return false;
+        };
+        client.closed.notify_one();
+        #[cfg(feature = "metrics")]
+        crate::metrics::global().set_clients(self.clients.lock().await.len());
+
+        self.router.handle_disconnect(client_id).await;
+        let close = async { client.sender.lock().await.send(Message::Close(None)).await };
+        let _ = tokio::time::timeout(self.send_timeout, close).await;
+        true
+    }
+
+    /// Derruba todas as sessões vindas desse IP. Devolve quantas eram.
+    pub async fn kick_ip(&self, ip: &IpAddr) -> usize {
+        let ids: Vec<ClientId> = self
+            .clients
+            .lock()
+            .await
+            .values()
+            .filter(|c| c.ip.as_ref() == Some(ip))
+            .map(|c| c.id)
+            .collect();
+
+        for id in ids.iter() {
+            self.kick(id).await;
+        }
+        ids.len()
+    }
+
+    pub async  fn on_connect(&self, sender: SplitSink<WebSocket, Message>, ip: Option<IpAddr>) -> (ClientId, Arc<Notify>) {
+        let client = Client::new(sender, ip);
+        let closed = Arc::clone(&client.closed);
+
+        {
+            let mut clients = self.clients.lock().await;
+            clients.insert(client.id, client.clone());
+            #[cfg(feature = "metrics")]
+            crate::metrics::global().set_clients(clients.len());
+        }
+        tracing::info!(client = %client.id, ip = ?client.ip, "cliente conectado");
+        // só abre a sessão; a nave entra quando o cliente manda `connect`
+        self.unicast(&client.id, format!("session:{}", client.id)).await;
+        let shapes = AsteroidCollection::shapes_json(&self.router.game().lock().await.world);
+        self.unicast(&client.id, shapes).await;
+
+        (client.id, closed)
     }
 
     pub async fn on_message(&self, client_id: &ClientId, message: Message) {
-        if let Message::Text(txt) = message {
-            if let Ok(payload) = serde_json::from_str::<ClientMessage>(&txt) {
+        let Message::Text(txt) = message else {
+            return;
+        };
+        // sessão derrubada (kick, ban, afk): o que ainda estava no caminho não vale
+        if !self.clients.lock().await.contains_key(client_id) {
+            return;
+        }
+
+        let payload = serde_json::from_str::<ClientMessage>(&txt);
+        #[cfg(feature = "metrics")]
+        crate::metrics::global().count_message(payload.as_ref().map_or("invalid", |p| p.name()));
+
+        match payload {
+            Ok(payload) => {
                 let response = self.router.handle_message(client_id, &payload).await;
                 self.handle_response(client_id, response).await;
             }
+            Err(e) => tracing::debug!(error = %e, "mensagem ignorada"),
         }
     }
 
     async fn handle_response(&self, client_id: &ClientId, response: WsResponse) {
          match response {
             WsResponse::Unicast(id, msg)  => self.unicast(&id, msg).await,
-            WsResponse::Broadcast(msg)          => self.broadcast(msg).await,
+            WsResponse::Broadcast(msg)          => { self.broadcast(msg).await; }
             WsResponse::Error(msg)              => self.unicast(client_id, msg).await,
             WsResponse::Nothing                         => {}
         }
     }
 
     pub async fn on_disconnect(&self, client_id: &ClientId) {
-        self.clients.lock().await.remove(&client_id);
+        {
+            let mut clients = self.clients.lock().await;
+            clients.remove(client_id);
+            #[cfg(feature = "metrics")]
+            crate::metrics::global().set_clients(clients.len());
+        }
         self.router.handle_disconnect(client_id).await;
-        println!("Cliente {} desconectado", client_id);
+        tracing::info!("cliente desconectado");
     }
 
-    pub async fn handle_socket(self: Arc<Self>, socket: WebSocket) {
-        let (sender, mut receiver) = socket.split();
+    pub async fn handle_socket(self: Arc<Self>, socket: WebSocket, ip: Option<IpAddr>) {
+        let (mut sender, mut receiver) = socket.split();
 
-        let client_id = self.on_connect(sender).await;
+        if self.is_shutting_down() {
+            let _ = sender.send(Message::Close(None)).await;
+            return;
+        }
 
-        // loop de mensagens
-        while let Some(Ok(msg)) = receiver.next().await {
-            self.on_message(&client_id, msg).await;
+        if let Some(ip) = ip
+            && self.is_banned(&ip).await
+        {
+            tracing::info!(%ip, "conexão recusada: IP banido");
+            let _ = sender.send(Message::Close(None)).await;
+            return;
         }
 
-        // on_disconnect
-        self.on_disconnect(&client_id).await;
+        let (client_id, closed) = self.on_connect(sender, ip).await;
+        let span = tracing::info_span!("client", id = %client_id);
+
+        let heartbeat = self.router.game().lock().await.config().heartbeat.clone();
+        let timeout = Duration::from_secs_f32(heartbeat.timeout_secs);
+
+        async {
+            let mut ping = tokio::time::interval(Duration::from_secs_f32(heartbeat.interval_secs));
+            // qualquer frame (inclusive o pong) prova que a conexão está viva
+            let mut last_seen = Instant::now();
+
+            loop {
+                tokio::select! {
+                    msg = receiver.next() => match msg {
+                        Some(Ok(msg)) => {
+                            last_seen = Instant::now();
+                            self.on_message(&client_id, msg).await;
+                        }
+                        _ => break,
+                    },
+                    _ = closed.notified() => break,
+                    _ = ping.tick() => {
+                        if last_seen.elapsed() > timeout {
+                            tracing::info!(silent_secs = last_seen.elapsed().as_secs_f32(), "conexão sem resposta");
+                            break;
+                        }
+                        self.send(&client_id, Message::Ping(Default::default())).await;
+                    }
+                }
+            }
+
+            // on_disconnect
+            self.on_disconnect(&client_id).await;
+        }
+        .instrument(span)
+        .await
     }
 
-    pub async fn broadcast(&self, msg: String) {
+    /// Devolve quantos bytes foram pra fila (mensagem × clientes).
+    pub async fn broadcast(&self, msg: String) -> usize {
         let clients = self.clients.lock().await;
+        let bytes = msg.len() * clients.len();
+        #[cfg(feature = "metrics")]
+        crate::metrics::global().add_sent_bytes(bytes);
 
+        // texto convertido uma vez; cada cliente só ganha uma referência
+        let msg: Utf8Bytes = msg.into();
         for client in clients.values() {
             let sender = client.sender.clone();
             let msg = msg.clone();
+            let timeout = self.send_timeout;
 
             tokio::spawn(async move {
-                let _ = sender.lock().await.send(Message::Text(msg.into())).await;
+                let _ = tokio::time::timeout(timeout, async { sender.lock().await.send(Message::Text(msg)).await }).await;
             });
         }
+
+        bytes
     }
 
     pub async fn unicast(&self, client_id: &ClientId, msg: String) {
-        let clients = self.clients.lock().await;
+        #[cfg(feature = "metrics")]
+        crate::metrics::global().add_sent_bytes(msg.len());
+        self.send(client_id, Message::Text(msg.into())).await;
+    }
 
-        if let Some(client) = clients.get(client_id) {
-            let _ = client.sender.lock().await.send(Message::Text(msg.into())).await;
+    /// Escreve fora do lock do mapa: um par meio aberto com o buffer cheio
+    /// só trava o próprio envio, e quem passa de `send_timeout` cai.
+    async fn send(&self, client_id: &ClientId, msg: Message) {
+        let Some(sender) = self.clients.lock().await.get(client_id).map(|c| Arc::clone(&c.sender)) else {
+            return;
+        };
+
+        let write = async { sender.lock().await.send(msg).await };
+        if tokio::time::timeout(self.send_timeout, write).await.is_err() {
+            tracing::info!(client = %client_id, timeout_secs = self.send_timeout.as_secs_f32(), "envio travado, derrubando");
+            self.kick(client_id).await;
         }
     }
 
-    pub async fn start(self: Arc<Self>) {
-        loop {
-            self.broadcast(
-                self.router.game_tick().await
-            ).await;
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+
+    /// Desligamento: para o tick, registra o placar final, avisa todo mundo
+    /// com `server_shutdown` (e onde reconectar, se houver) e fecha os
+    /// sockets. Espera no máximo `grace` pelos envios. Devolve quantos
+    /// clientes foram avisados.
+    pub async fn shutdown(&self, reconnect: Option<&str>, grace: Duration) -> usize {
+        self.shutting_down.store(true, Ordering::SeqCst);
+
+        {
+            let mut game = self.router.game().lock().await;
+            for (id, score, team) in game.scoreboard() {
+                tracing::info!(target: "scores", player = %id, score, team, "placar final");
+            }
+            game.finish_recording();
+        }
+
+        let msg = serde_json::json!({ "type": "server_shutdown", "reconnect": reconnect }).to_string();
+        let clients: Vec<Client> = self.clients.lock().await.drain().map(|(_, client)| client).collect();
+        #[cfg(feature = "metrics")]
+        crate::metrics::global().set_clients(0);
+
+        let sends = clients.iter().map(|client| {
+            let msg = msg.clone();
+            async move {
+                let mut sender = client.sender.lock().await;
+                let _ = sender.send(Message::Text(msg.into())).await;
+                let _ = sender.send(Message::Close(None)).await;
+            }
+        });
+
+        if tokio::time::timeout(grace, join_all(sends)).await.is_err() {
+            tracing::warn!(grace_secs = grace.as_secs_f32(), "nem todos os clientes receberam o aviso a tempo");
         }
 
+        clients.len()
+    }
+
+    /// Loop de tick; termina quando o servidor começa a desligar.
+    pub async fn start(self: Arc<Self>) {
+        while !self.is_shutting_down() {
+            let state = self.router.game_tick().await;
+            #[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+            let bytes = self.broadcast(state).await;
+
+            #[cfg(feature = "metrics")]
+            crate::metrics::global().set_tick_sent_bytes(bytes);
+
+            let idle = self.router.game().lock().await.drain_idle_kicks();
+            for id in idle {
+                self.kick(&id).await;
+            }
+        }
     }
 
 }
@@ -0,0 +1,346 @@
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+
+use uuid::Uuid;
+
+use crate::config::GameConfig;
+use crate::entities::asteroid::AsteroidType;
+use crate::entities::weapon::WeaponKind;
+use crate::game::GameManager;
+use crate::networking::router::MovePayload;
+use crate::types::{ClientId, TeamId};
+
+/// Arquivo de replay: `MAGIC`, versão, seed, config (TOML) e depois um
+/// registro por entrada, cada um com o tick em que chegou.
+pub const MAGIC: &[u8; 4] = b"ASRP";
+pub const VERSION: u8 = 2;
+
+const JOIN: u8 = 1;
+const LEAVE: u8 = 2;
+const MOVE: u8 = 3;
+const CHOOSE_TEAM: u8 = 4;
+const SPAWN_ASTEROID: u8 = 5;
+const CLEAR_FIELD: u8 = 6;
+const RELOAD_CONFIG: u8 = 7;
+const CHOOSE_WEAPON: u8 = 8;
+const END: u8 = 0xFF;
+
+/// Tudo que vem de fora da simulação e muda o resultado dela. O que o
+/// próprio jogo decide (AFK, mortes, ondas) sai igual ao re-simular.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReplayInput {
+    Join(ClientId),
+    Leave(ClientId),
+    Move(ClientId, u8),
+    ChooseTeam(ClientId, TeamId),
+    ChooseWeapon(ClientId, WeaponKind),
+    SpawnAsteroid { x: f32, y: f32, tier: AsteroidType },
+    ClearField,
+    ReloadConfig(Box<GameConfig>),
+}
+
+/// Entrada aplicada antes do tick `tick + 1` (ou seja, com `tick` já simulados).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReplayRecord {
+    pub tick: u32,
+    pub input: ReplayInput,
+}
+
+/// `thrust`, `left`, `right`, `fire` num byte.
+pub fn move_flags(command: &MovePayload) -> u8 {
+    command.thrust as u8 | (command.left as u8) << 1 | (command.right as u8) << 2 | (command.fire as u8) << 3
+}
+
+pub fn move_payload(flags: u8) -> MovePayload {
+    MovePayload {
+        thrust: flags & 1 != 0,
+        left: flags & 2 != 0,
+        right: flags & 4 != 0,
+        fire: flags & 8 != 0,
+    }
+}
+
+/// A config vai dentro do arquivo; o segredo do admin não.
+pub fn sanitized(config: &GameConfig) -> GameConfig {
+    let mut config = config.clone();
+    config.admin.secret.clear();
+    config
+}
+
+/// Grava as entradas conforme chegam. Clonável (o `GameManager` é) e os
+/// clones escrevem no mesmo destino.
+#[derive(Clone)]
+pub struct Recorder {
+    out: Arc<Mutex<Box<dyn Write + Send>>>,
+}
+
+impl Recorder {
+    /// Escreve o cabeçalho. `config` já deve vir de `sanitized`.
+    pub fn start(mut out: Box<dyn Write + Send>, seed: u64, config: &GameConfig) -> io::Result<Self> {
+        let config = toml::to_string(config).map_err(io::Error::other)?;
+
+        out.write_all(MAGIC)?;
+        out.write_all(&[VERSION])?;
+        out.write_all(&seed.to_le_bytes())?;
+        write_text(&mut out, &config)?;
+
+        Ok(Self { out: Arc::new(Mutex::new(out)) })
+    }
+
+    pub fn record(&self, tick: u64, input: &ReplayInput) {
+        let mut bytes = Vec::with_capacity(24);
+        bytes.push(kind(input));
+        bytes.extend_from_slice(&(tick as u32).to_le_bytes());
+
+        match input {
+            ReplayInput::Join(id) | ReplayInput::Leave(id) => bytes.extend_from_slice(id.as_bytes()),
+            ReplayInput::Move(id, flags) => {
+                bytes.extend_from_slice(id.as_bytes());
+                bytes.push(*flags);
+            }
+            ReplayInput::ChooseTeam(id, team) => {
+                bytes.extend_from_slice(id.as_bytes());
+                bytes.push(*team);
+            }
+            ReplayInput::ChooseWeapon(id, weapon) => {
+                bytes.extend_from_slice(id.as_bytes());
+                bytes.push(weapon_byte(*weapon));
+            }
+            ReplayInput::SpawnAsteroid { x, y, tier } => {
+                bytes.extend_from_slice(&x.to_le_bytes());
+                bytes.extend_from_slice(&y.to_le_bytes());
+                bytes.push(tier_byte(*tier));
+            }
+            ReplayInput::ClearField => {}
+            ReplayInput::ReloadConfig(config) => match toml::to_string(config.as_ref()) {
+                Ok(text) => {
+                    let _ = write_text(&mut bytes, &text);
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "replay: entrada não gravada");
+                    return;
+                }
+            },
+        }
+
+        self.write(&bytes);
+    }
+
+    /// Marca o fim (quantos ticks a partida teve) e descarrega o buffer.
+    pub fn finish(&self, tick: u64) {
+        let mut bytes = vec![END];
+        bytes.extend_from_slice(&(tick as u32).to_le_bytes());
+        self.write(&bytes);
+
+        if let Ok(mut out) = self.out.lock()
+            && let Err(e) = out.flush()
+        {
+            tracing::warn!(error = %e, "replay: falha ao descarregar");
+        }
+    }
+
+    fn write(&self, bytes: &[u8]) {
+        if let Ok(mut out) = self.out.lock()
+            && let Err(e) = out.write_all(bytes)
+        {
+            tracing::warn!(error = %e, "replay: falha ao gravar");
+        }
+    }
+}
+
+/// Partida lida de um arquivo de replay.
+#[derive(Clone, Debug)]
+pub struct Replay {
+    pub seed: u64,
+    pub config: GameConfig,
+    pub records: Vec<ReplayRecord>,
+    /// Ticks simulados até o fim da gravação (sem o fim, o tick do último registro).
+    pub ticks: u32,
+}
+
+impl Replay {
+    pub fn from_file(path: &str) -> Result<Replay, String> {
+        let bytes = std::fs::read(path).map_err(|e| format!("{}: {}", path, e))?;
+        Self::decode(&bytes).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Replay, String> {
+        let mut reader = Reader { bytes, pos: 0 };
+
+        if reader.take(4)? != MAGIC {
+            return Err("não é um arquivo de replay".to_string());
+        }
+        let version = reader.u8()?;
+        if version != VERSION {
+            return Err(format!("versão de replay {} não suportada", version));
+        }
+
+        let seed = reader.u64()?;
+        let config = GameConfig::from_toml(&reader.text()?)?;
+
+        let mut records = Vec::new();
+        let mut end = None;
+
+        while !reader.is_empty() {
+            let kind = reader.u8()?;
+            let tick = reader.u32()?;
+
+            let input = match kind {
+                JOIN => ReplayInput::Join(reader.id()?),
+                LEAVE => ReplayInput::Leave(reader.id()?),
+                MOVE => ReplayInput::Move(reader.id()?, reader.u8()?),
+                CHOOSE_TEAM => ReplayInput::ChooseTeam(reader.id()?, reader.u8()?),
+                CHOOSE_WEAPON => ReplayInput::ChooseWeapon(reader.id()?, weapon_from_byte(reader.u8()?)?),
+                SPAWN_ASTEROID => ReplayInput::SpawnAsteroid {
+                    x: f32::from_bits(reader.u32()?),
+                    y: f32::from_bits(reader.u32()?),
+                    tier: tier_from_byte(reader.u8()?)?,
+                },
+                CLEAR_FIELD => ReplayInput::ClearField,
+                RELOAD_CONFIG => ReplayInput::ReloadConfig(Box::new(GameConfig::from_toml(&reader.text()?)?)),
+                END => {
+                    end = Some(tick);
+                    break;
+                }
+                other => return Err(format!("registro desconhecido: {}", other)),
+            };
+
+            records.push(ReplayRecord { tick, input });
+        }
+
+        let ticks = end.or_else(|| records.last().map(|r| r.tick)).unwrap_or(0);
+        Ok(Replay { seed, config, records, ticks })
+    }
+
+    /// Re-simula a partida do zero. `on_tick` roda depois de cada tick, no
+    /// mesmo ponto em que o servidor tira o snapshot.
+    pub fn play(&self, mut on_tick: impl FnMut(&mut GameManager)) -> GameManager {
+        let mut game = GameManager::with_config(self.config.clone(), Some(self.seed));
+        let mut records = self.records.iter().peekable();
+
+        for tick in 0..self.ticks {
+            while let Some(record) = records.next_if(|r| r.tick == tick) {
+                apply(&mut game, &record.input);
+            }
+            game.tick();
+            on_tick(&mut game);
+        }
+
+        for record in records {
+            apply(&mut game, &record.input);
+        }
+        game
+    }
+}
+
+fn apply(game: &mut GameManager, input: &ReplayInput) {
+    match input {
+        ReplayInput::Join(id) => {
+            let _ = game.add_player(id);
+        }
+        ReplayInput::Leave(id) => {
+            game.remove_player(id);
+        }
+        ReplayInput::Move(id, flags) => game.handle_player_command(id, &move_payload(*flags)),
+        ReplayInput::ChooseTeam(id, team) => {
+            let _ = game.choose_team(id, *team);
+        }
+        ReplayInput::ChooseWeapon(id, weapon) => {
+            let _ = game.choose_weapon(id, *weapon);
+        }
+        ReplayInput::SpawnAsteroid { x, y, tier } => {
+            game.spawn_asteroid(*x, *y, *tier);
+        }
+        ReplayInput::ClearField => game.clear_field(),
+        ReplayInput::ReloadConfig(config) => {
+            let _ = game.reload_config(config.as_ref().clone());
+        }
+    }
+}
+
+fn kind(input: &ReplayInput) -> u8 {
+    match input {
+        ReplayInput::Join(_) => JOIN,
+        ReplayInput::Leave(_) => LEAVE,
+        ReplayInput::Move(..) => MOVE,
+        ReplayInput::ChooseTeam(..) => CHOOSE_TEAM,
+        ReplayInput::ChooseWeapon(..) => CHOOSE_WEAPON,
+        ReplayInput::SpawnAsteroid { .. } => SPAWN_ASTEROID,
+        ReplayInput::ClearField => CLEAR_FIELD,
+        ReplayInput::ReloadConfig(_) => RELOAD_CONFIG,
+    }
+}
+
+fn write_text(out: &mut impl Write, text: &str) -> io::Result<()> {
+    out.write_all(&(text.len() as u32).to_le_bytes())?;
+    out.write_all(text.as_bytes())
+}
+
+fn tier_byte(tier: AsteroidType) -> u8 {
+    match tier {
+        AsteroidType::SMALL => 0,
+        AsteroidType::MEDIUM => 1,
+        AsteroidType::BIG => 2,
+    }
+}
+
+fn tier_from_byte(byte: u8) -> Result<AsteroidType, String> {
+    match byte {
+        0 => Ok(AsteroidType::SMALL),
+        1 => Ok(AsteroidType::MEDIUM),
+        2 => Ok(AsteroidType::BIG),
+        other => Err(format!("tamanho de asteroide inválido: {}", other)),
+    }
+}
+
+fn weapon_byte(weapon: WeaponKind) -> u8 {
+    WeaponKind::ALL.iter().position(|w| *w == weapon).unwrap_or(0) as u8
+}
+
+fn weapon_from_byte(byte: u8) -> Result<WeaponKind, String> {
+    WeaponKind::ALL
+        .get(byte as usize)
+        .copied()
+        .ok_or_else(|| format!("arma inválida: {}", byte))
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], String> {
+        let slice = self
+            .bytes
+            .get(self.pos..self.pos + n)
+            .ok_or_else(|| "arquivo de replay truncado".to_string())?;
+        self.pos += n;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap_or_default()))
+    }
+
+    fn u64(&mut self) -> Result<u64, String> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap_or_default()))
+    }
+
+    fn id(&mut self) -> Result<Uuid, String> {
+        Ok(Uuid::from_bytes(self.take(16)?.try_into().unwrap_or_default()))
+    }
+
+    fn text(&mut self) -> Result<String, String> {
+        let len = self.u32()? as usize;
+        String::from_utf8(self.take(len)?.to_vec()).map_err(|e| e.to_string())
+    }
+}
@@ -1,13 +1,26 @@
-use uuid::Uuid;
-
 use crate::collections::asteroid_collection::AsteroidCollection;
-use crate::collections::bullet_collection::BulletCollection;
 use crate::collections::player_collection::PlayerCollection;
-use crate::entities::hitbox::{EntityKind, HitBox};
+use crate::collections::powerup_collection::PowerUpCollection;
+use crate::collections::ufo_collection::UfoCollection;
+use crate::config::PhysicsConfig;
+use crate::entities::hitbox::{BodyId, EntityKind, HitBox, LAYER_ASTEROID};
+use crate::entities::traits::collision_object::CollisionObject;
+use crate::entities::player::Damage;
+use crate::events::GameEvent;
+use crate::systems::physics::{self, Body};
+use crate::types::{ClientId, EntityId};
+use crate::world::World;
+use crate::world::components::Velocity;
 
-struct Hit {
-    a: (EntityKind, Uuid),
-    b: (EntityKind, Uuid)
+enum Hit {
+    BulletPlayer(EntityId, ClientId),
+    BulletAsteroid(EntityId, EntityId),
+    PlayerAsteroid(ClientId, EntityId),
+    PlayerPowerUp(ClientId, EntityId),
+    BulletUfo(EntityId, EntityId),
+    PlayerUfo(ClientId, EntityId),
+    /// Só aparece com a física ligada (é ela que liga o layer).
+    AsteroidAsteroid(EntityId, EntityId),
 }
 
 impl Hit {
@@ -15,21 +28,28 @@ impl Hit {
 
         let (a, b) = HitBox::ord(x, y);
 
-        match (a.kind, b.kind) {
-            (EntityKind::Bullet, EntityKind::Player) => {
-                Some(Hit {
-                    a: (EntityKind::Bullet, a.id),
-                    b: (EntityKind::Player, b.id)
-                })
+        match (a.kind, a.id, b.kind, b.id) {
+            (EntityKind::Bullet, BodyId::Entity(bullet), EntityKind::Player, BodyId::Player(player)) => {
+                Some(Hit::BulletPlayer(bullet, player))
+            }
+            (EntityKind::Bullet, BodyId::Entity(bullet), EntityKind::Asteroid, BodyId::Entity(asteroid)) => {
+                Some(Hit::BulletAsteroid(bullet, asteroid))
+            }
+            (EntityKind::Player, BodyId::Player(player), EntityKind::Asteroid, BodyId::Entity(asteroid)) => {
+                Some(Hit::PlayerAsteroid(player, asteroid))
+            }
+            (EntityKind::Player, BodyId::Player(player), EntityKind::PowerUp, BodyId::Entity(powerup)) => {
+                Some(Hit::PlayerPowerUp(player, powerup))
+            }
+            (EntityKind::Bullet, BodyId::Entity(bullet), EntityKind::Ufo, BodyId::Entity(ufo)) => {
+                Some(Hit::BulletUfo(bullet, ufo))
+            }
+            (EntityKind::Player, BodyId::Player(player), EntityKind::Ufo, BodyId::Entity(ufo)) => {
+                Some(Hit::PlayerUfo(player, ufo))
+            }
+            (EntityKind::Asteroid, BodyId::Entity(a), EntityKind::Asteroid, BodyId::Entity(b)) => {
+                Some(Hit::AsteroidAsteroid(a, b))
             }
-            (EntityKind::Bullet, EntityKind::Asteroid) => Some(Hit {
-                a: (EntityKind::Bullet, a.id),
-                b: (EntityKind::Asteroid, b.id)
-            }),
-            (EntityKind::Player, EntityKind::Asteroid) => Some(Hit {
-                a: (EntityKind::Player, a.id),
-                b: (EntityKind::Asteroid, b.id)
-            }),
 
             _ => None,
         }
@@ -40,74 +60,217 @@ pub struct CollisionSystem;
 
 impl CollisionSystem {
     pub fn run(
+        world: &mut World,
         players: &mut PlayerCollection,
-        bullets: &mut BulletCollection,
         asteroids: &mut AsteroidCollection,
+        friendly_fire: bool,
+        physics: Option<&PhysicsConfig>,
+        events: &mut Vec<GameEvent>,
     ) {
-
-        let mut boxes = players.get_hitboxes();
-        boxes.extend(bullets.get_hitboxes());
-        boxes.extend(asteroids.get_hitboxes());
-
-        let hits = CollisionSystem::colide(&boxes);
+        let boxes = Self::boxes(world, players, friendly_fire, physics.is_some());
+        let hits: Vec<Hit> = (0..boxes.len()).flat_map(|i| Self::hits_from(&boxes, i)).collect();
 
         for hit in hits {
-            Self::resolve(players, bullets, asteroids, &hit);
+            Self::resolve(world, players, asteroids, &hit, physics, events);
         }
     }
 
+    /// Como `run`, com os pares testados em paralelo. Os hits saem na mesma
+    /// ordem do serial e a resolução continua sequencial.
+    #[cfg(feature = "parallel")]
+    pub fn run_parallel(
+        world: &mut World,
+        players: &mut PlayerCollection,
+        asteroids: &mut AsteroidCollection,
+        friendly_fire: bool,
+        physics: Option<&PhysicsConfig>,
+        events: &mut Vec<GameEvent>,
+    ) {
+        use rayon::prelude::*;
 
-    fn colide(boxes: &Vec<HitBox>) -> Vec<Hit> {
-        let mut hits: Vec<Hit> = Vec::new();
+        let boxes = Self::boxes(world, players, friendly_fire, physics.is_some());
+        let hits: Vec<Hit> = (0..boxes.len())
+            .into_par_iter()
+            .flat_map_iter(|i| Self::hits_from(&boxes, i))
+            .collect();
 
-        for i in 0..boxes.len() {
-            for j in (i + 1)..boxes.len() {
-                let a = &boxes[i];
-                let b = &boxes[j];
+        for hit in hits {
+            Self::resolve(world, players, asteroids, &hit, physics, events);
+        }
+    }
 
-                if !( a.should_collide(b) && a.intersects(b) ) {
-                    continue;
-                }
+    fn boxes(world: &World, players: &PlayerCollection, friendly_fire: bool, bounce: bool) -> Vec<HitBox> {
+        let mut boxes = players.get_hitboxes();
+        boxes.extend(world.hitboxes());
 
-                if let Some(hit) = Hit::from_pair(a, b) {
-                    hits.push(hit);
-                }
-            }
+        // com fogo amigo, time não isenta ninguém
+        if friendly_fire {
+            boxes.iter_mut().for_each(|b| b.team = None);
+        }
+        // com física, asteroide também enxerga asteroide
+        if bounce {
+            boxes.iter_mut().filter(|b| b.kind == EntityKind::Asteroid).for_each(|b| b.mask |= LAYER_ASTEROID);
         }
-        hits
+        boxes
+    }
+
+    /// Pares `(i, j)` com `j > i` que colidem.
+    fn hits_from(boxes: &[HitBox], i: usize) -> impl Iterator<Item = Hit> + '_ {
+        let a = &boxes[i];
+
+        boxes[i + 1..]
+            .iter()
+            .filter(move |b| a.should_collide(b) && a.intersects(b))
+            .filter_map(move |b| Hit::from_pair(a, b))
     }
 
 
     fn resolve(
+        world: &mut World,
         players: &mut PlayerCollection,
-        bullets: &mut BulletCollection,
         asteroids: &mut AsteroidCollection,
         hit: &Hit,
+        physics: Option<&PhysicsConfig>,
+        events: &mut Vec<GameEvent>,
     ) {
-        // pares vêm normalizados por rank: Bullet < Player < Asteroid
-        let (a_kind, a_id) = hit.a;
-        let (b_kind, b_id) = hit.b;
+        // pares vêm normalizados por rank: Bullet < Player < Asteroid < PowerUp < Ufo
+        match *hit {
 
-        match (a_kind, b_kind) {
-            
-            (EntityKind::Bullet, EntityKind::Player) => {
-                if bullets.get_owner(&a_id) == Some(b_id) {
+            Hit::BulletPlayer(bullet, player) => {
+                // bala já gasta em outro par deste tick não acerta mais nada
+                if !world.contains(bullet) {
+                    return;
+                }
+                let Some(&projectile) = world.projectiles.get(bullet) else {
+                    return;
+                };
+                let owner = world.owners.get(bullet).map(|o| o.player);
+                if owner == Some(player) {
                     return; 
                 }
-                bullets.rm_bullet(a_id);
-                players.rm_player(&b_id);
+                world.despawn(bullet);
+                let source = if projectile.hostile { Damage::Ufo } else { Damage::Bullet(owner, projectile.damage) };
+                events.extend(players.damage(&player, source));
             }
             
-            (EntityKind::Bullet, EntityKind::Asteroid) => {
-                bullets.rm_bullet(a_id);
-                asteroids.remove_by_id(b_id);
+            Hit::BulletAsteroid(bullet, asteroid) => {
+                if !world.contains(bullet) {
+                    return;
+                }
+                let owner = world.owners.get(bullet).map(|o| o.player);
+                let impact = world.transforms.get(bullet).map(|t| t.angle);
+                world.despawn(bullet);
+                asteroids.remove_by_id(world, asteroid, owner, impact, events);
             }
             
-            (EntityKind::Player, EntityKind::Asteroid) => {
-                players.rm_player(&a_id);
-                asteroids.remove_by_id(b_id);
+            Hit::PlayerAsteroid(player, asteroid) => {
+                // nave destruída antes no mesmo tick não bate nem leva crédito
+                if !players.is_alive(&player) {
+                    return;
+                }
+                if let Some(physics) = physics {
+                    Self::bump_player(world, players, player, asteroid, physics, events);
+                    return;
+                }
+
+                if let Some(&tier) = world.tiers.get(asteroid) {
+                    events.extend(players.damage(&player, Damage::Asteroid(tier)));
+                }
+                asteroids.remove_by_id(world, asteroid, Some(player), None, events);
+            }
+
+            Hit::PlayerPowerUp(player, powerup) => {
+                // quem morreu antes no mesmo tick não pega
+                let Some(ship) = players.get_player_mut(&player).filter(|p| !p.is_destroyed()) else {
+                    return;
+                };
+                if let Some(kind) = PowerUpCollection::collect(world, powerup, player, events) {
+                    ship.apply_powerup(kind);
+                }
+            }
+
+            Hit::BulletUfo(bullet, ufo) => {
+                if !world.contains(bullet) {
+                    return;
+                }
+                let Some(&projectile) = world.projectiles.get(bullet) else {
+                    return;
+                };
+                if projectile.hostile {
+                    return;
+                }
+                let owner = world.owners.get(bullet).map(|o| o.player);
+                world.despawn(bullet);
+                UfoCollection::damage(world, ufo, projectile.damage, owner, events);
+            }
+
+            Hit::PlayerUfo(player, ufo) => {
+                // trombada: a nave apanha e o disco cai, creditado a ela
+                if !players.is_alive(&player) {
+                    return;
+                }
+                if !UfoCollection::destroy(world, ufo, Some(player), events) {
+                    return;
+                }
+                events.extend(players.damage(&player, Damage::Ufo));
+            }
+
+            Hit::AsteroidAsteroid(a, b) => {
+                if let Some(physics) = physics {
+                    Self::bump_asteroids(world, a, b, physics);
+                }
             }
-            _ => {}
         }
     }
+
+    /// Asteroide como corpo da física; None se já saiu nesse tick.
+    fn asteroid_body(world: &World, id: EntityId) -> Option<Body> {
+        let transform = world.transforms.get(id)?;
+        Some(Body {
+            hitbox: world.colliders.get(id)?.hitbox(id, transform, None),
+            velocity: world.velocities.get(id).copied().unwrap_or_default(),
+            mass: world.tiers.get(id)?.mass(),
+        })
+    }
+
+    fn apply_to_asteroid(world: &mut World, id: EntityId, (dx, dy): (f32, f32), velocity: Velocity) {
+        if let Some(transform) = world.transforms.get_mut(id) {
+            transform.x += dx;
+            transform.y += dy;
+        }
+        world.velocities.insert(id, velocity);
+    }
+
+    fn bump_asteroids(world: &mut World, a: EntityId, b: EntityId, config: &PhysicsConfig) {
+        let (Some(body_a), Some(body_b)) = (Self::asteroid_body(world, a), Self::asteroid_body(world, b)) else {
+            return;
+        };
+        let contact = physics::bounce(&body_a, &body_b, config.restitution);
+        Self::apply_to_asteroid(world, a, contact.push_a, contact.velocity_a);
+        Self::apply_to_asteroid(world, b, contact.push_b, contact.velocity_b);
+    }
+
+    /// A nave quica no asteroide e toma dano pela força da batida. O
+    /// asteroide segue inteiro.
+    fn bump_player(
+        world: &mut World,
+        players: &mut PlayerCollection,
+        player: ClientId,
+        asteroid: EntityId,
+        config: &PhysicsConfig,
+        events: &mut Vec<GameEvent>,
+    ) {
+        let (Some(ship), Some(rock)) = (players.get_player_mut(&player), Self::asteroid_body(world, asteroid)) else {
+            return;
+        };
+        let body = Body { hitbox: ship.hitbox(), velocity: ship.velocity(), mass: config.ship_mass };
+        let contact = physics::bounce(&body, &rock, config.restitution);
+
+        let (transform, velocity) = ship.body_mut();
+        transform.x += contact.push_a.0;
+        transform.y += contact.push_a.1;
+        *velocity = contact.velocity_a;
+        Self::apply_to_asteroid(world, asteroid, contact.push_b, contact.velocity_b);
+        events.extend(players.damage(&player, Damage::Collision(contact.closing)));
+    }
 }
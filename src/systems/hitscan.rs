@@ -0,0 +1,113 @@
+use crate::collections::asteroid_collection::AsteroidCollection;
+use crate::collections::player_collection::PlayerCollection;
+use crate::collections::ufo_collection::UfoCollection;
+use crate::entities::bullet::Bullet;
+use crate::entities::hitbox::{BodyId, EntityKind, HitBox, Point, Shape};
+use crate::entities::player::Damage;
+use crate::entities::polygon;
+use crate::events::GameEvent;
+use crate::types::EntityId;
+use crate::world::World;
+
+/// Disparos instantâneos (laser): o raio sai da nave e acerta o primeiro
+/// alvo na linha, até `Bullet::range`. Não vira entidade nem dá a volta no
+/// mundo; asteroide conta pelo contorno, não pelo círculo que o envolve.
+pub struct HitscanSystem;
+
+impl HitscanSystem {
+    pub fn run(
+        world: &mut World,
+        players: &mut PlayerCollection,
+        asteroids: &mut AsteroidCollection,
+        friendly_fire: bool,
+        shots: Vec<Bullet>,
+        events: &mut Vec<GameEvent>,
+    ) {
+        if shots.is_empty() {
+            return;
+        }
+
+        // montados uma vez por tick; cada acerto só mexe no alvo atingido
+        let mut targets = players.get_hitboxes();
+        targets.extend(world.hitboxes().into_iter().filter(|b| matches!(b.kind, EntityKind::Asteroid | EntityKind::Ufo)));
+
+        for shot in shots {
+            let (dx, dy) = (shot.angle.cos(), shot.angle.sin());
+            let hit = targets
+                .iter()
+                .filter(|b| b.id != BodyId::Player(shot.player_id))
+                .filter(|b| friendly_fire || shot.team.is_none() || b.team != shot.team)
+                .filter_map(|b| Self::entry(&shot, (dx, dy), b).map(|t| (t, b.id)))
+                .min_by(|a, b| a.0.total_cmp(&b.0));
+
+            let length = hit.map_or(shot.range(), |(t, _)| t);
+            events.push(GameEvent::LaserFired { owner: shot.player_id, x: shot.x, y: shot.y, angle: shot.angle, length });
+
+            let Some((_, id)) = hit else {
+                continue;
+            };
+            let gone = match id {
+                BodyId::Player(player) => {
+                    let killed = players.damage(&player, Damage::Bullet(Some(shot.player_id), shot.damage));
+                    let gone = killed.is_some();
+                    events.extend(killed);
+                    gone
+                }
+                BodyId::Entity(ufo) if world.kind(ufo) == Some(EntityKind::Ufo) => {
+                    UfoCollection::damage(world, ufo, shot.damage, Some(shot.player_id), events)
+                }
+                BodyId::Entity(asteroid) => {
+                    let from = events.len();
+                    let gone = asteroids.remove_by_id(world, asteroid, Some(shot.player_id), Some(shot.angle), events);
+                    // os pedaços já entram na mira dos próximos disparos
+                    let children: Vec<EntityId> = events[from..]
+                        .iter()
+                        .filter_map(|e| match e {
+                            GameEvent::AsteroidSpawned { id, parent: Some(parent), .. } if *parent == asteroid => Some(*id),
+                            _ => None,
+                        })
+                        .collect();
+                    targets.extend(children.into_iter().filter_map(|child| Self::hitbox(world, child)));
+                    gone
+                }
+            };
+            if gone {
+                targets.retain(|b| b.id != id);
+            }
+        }
+    }
+
+    fn hitbox(world: &World, id: EntityId) -> Option<HitBox> {
+        let (collider, transform) = (world.colliders.get(id)?, world.transforms.get(id)?);
+        let team = world.owners.get(id).and_then(|owner| owner.team);
+        Some(collider.hitbox(id, transform, team))
+    }
+
+    /// Distância ao longo do raio em que ele entra no alvo, se entra.
+    fn entry(shot: &Bullet, (dx, dy): (f32, f32), target: &HitBox) -> Option<f32> {
+        let (ox, oy) = (target.pos.x - shot.x, target.pos.y - shot.y);
+        let radius = target.shape.radius();
+
+        // projeção do centro no raio e distância do centro à linha; o círculo
+        // que envolve a forma descarta rápido o que nem passa perto
+        let along = ox * dx + oy * dy;
+        let off_sq = (ox * ox + oy * oy) - along * along;
+        if off_sq > radius * radius {
+            return None;
+        }
+
+        let t = match &target.shape {
+            Shape::Circle { .. } => {
+                let inside = ox * ox + oy * oy <= radius * radius;
+                if !inside && along < 0.0 {
+                    return None;
+                }
+                (along - (radius * radius - off_sq).sqrt()).max(0.0)
+            }
+            Shape::Polygon(outline) => {
+                polygon::ray_polygon(Point { x: shot.x, y: shot.y }, Point { x: dx, y: dy }, target.pos, outline)?
+            }
+        };
+        (t <= shot.range()).then_some(t)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::collections::player_collection::PlayerCollection;
+use crate::entities::hitbox::{BodyId, EntityKind};
+use crate::world::World;
+use crate::world::components::Velocity;
+use std::f32::consts::{PI, TAU};
+
+/// Mísseis viram na direção da nave inimiga ou disco voador mais perto
+/// (pelo caminho mais curto, contando a volta do mundo). Roda antes do
+/// movimento.
+pub struct HomingSystem;
+
+impl HomingSystem {
+    pub fn run(world: &mut World, players: &PlayerCollection, friendly_fire: bool) {
+        let size = world.size();
+        let mut targets = players.get_hitboxes();
+        targets.extend(world.hitboxes().into_iter().filter(|b| b.kind == EntityKind::Ufo));
+
+        let turns: Vec<_> = world
+            .homing
+            .iter()
+            .filter_map(|(id, homing)| {
+                let transform = world.transforms.get(id)?;
+                let owner = world.owners.get(id)?;
+                let speed = world.velocities.get(id)?.speed();
+
+                let (dx, dy) = targets
+                    .iter()
+                    .filter(|b| b.id != BodyId::Player(owner.player))
+                    .filter(|b| friendly_fire || owner.team.is_none() || b.team != owner.team)
+                    .map(|b| (wrapped(b.pos.x - transform.x, size), wrapped(b.pos.y - transform.y, size)))
+                    .min_by(|a, b| (a.0 * a.0 + a.1 * a.1).total_cmp(&(b.0 * b.0 + b.1 * b.1)))?;
+
+                let diff = (dy.atan2(dx) - transform.angle + PI).rem_euclid(TAU) - PI;
+                let angle = transform.angle + diff.clamp(-homing.turn_rate, homing.turn_rate);
+                Some((id, angle, speed))
+            })
+            .collect();
+
+        for (id, angle, speed) in turns {
+            if let Some(transform) = world.transforms.get_mut(id) {
+                transform.angle = angle;
+            }
+            world.velocities.insert(id, Velocity::from_angle(speed, angle));
+        }
+    }
+}
+
+/// Menor deslocamento num eixo que dá a volta em `size`.
+fn wrapped(delta: f32, size: f32) -> f32 {
+    (delta + size / 2.0).rem_euclid(size) - size / 2.0
+}
@@ -0,0 +1,22 @@
+use crate::types::EntityId;
+use crate::world::World;
+
+/// Conta o tempo de vida e tira do mundo quem chegou a zero.
+pub struct LifetimeSystem;
+
+impl LifetimeSystem {
+    pub fn run(world: &mut World) {
+        let mut expired: Vec<EntityId> = Vec::new();
+
+        for (id, lifetime) in world.lifetimes.iter_mut() {
+            lifetime.ticks = lifetime.ticks.saturating_sub(1);
+            if lifetime.ticks == 0 {
+                expired.push(id);
+            }
+        }
+
+        for id in expired {
+            world.despawn(id);
+        }
+    }
+}
@@ -1 +1,8 @@
 pub mod collision;
+pub mod hitscan;
+pub mod homing;
+pub mod lifetime;
+pub mod movement;
+pub mod physics;
+pub mod waves;
+pub mod wrap;
@@ -0,0 +1,36 @@
+use crate::world::World;
+
+/// Soma a velocidade na posição e o giro no ângulo de quem tem os dois.
+pub struct MovementSystem;
+
+impl MovementSystem {
+    pub fn run(world: &mut World) {
+        for (id, velocity) in world.velocities.iter() {
+            if let Some(transform) = world.transforms.get_mut(id) {
+                transform.advance(velocity);
+            }
+        }
+        for (id, spin) in world.spins.iter() {
+            if let Some(transform) = world.transforms.get_mut(id) {
+                transform.rotate(spin);
+            }
+        }
+    }
+
+    /// Cada entidade só mexe no próprio `Transform`: dá o mesmo resultado
+    /// do serial em qualquer ordem.
+    #[cfg(feature = "parallel")]
+    pub fn run_parallel(world: &mut World) {
+        use rayon::prelude::*;
+
+        let (velocities, spins) = (&world.velocities, &world.spins);
+        world.transforms.par_iter_mut().for_each(|(id, transform)| {
+            if let Some(velocity) = velocities.get(id) {
+                transform.advance(velocity);
+            }
+            if let Some(spin) = spins.get(id) {
+                transform.rotate(spin);
+            }
+        });
+    }
+}
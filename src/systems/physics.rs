@@ -0,0 +1,70 @@
+use crate::entities::hitbox::{HitBox, Point};
+use crate::world::components::Velocity;
+
+/// Passos da busca pela separação; cada um corta o erro pela metade.
+const SEPARATION_STEPS: u32 = 8;
+
+/// Um lado da trombada: forma na posição atual, velocidade e massa.
+pub struct Body {
+    pub hitbox: HitBox,
+    pub velocity: Velocity,
+    pub mass: f32,
+}
+
+/// O que aplicar em cada lado depois da trombada.
+#[derive(Debug, PartialEq)]
+pub struct Contact {
+    /// Deslocamentos que tiram a sobreposição.
+    pub push_a: (f32, f32),
+    pub push_b: (f32, f32),
+    pub velocity_a: Velocity,
+    pub velocity_b: Velocity,
+    /// Velocidade de aproximação na normal (0 se já se afastavam).
+    pub closing: f32,
+}
+
+/// Choque entre dois corpos que se tocam. A normal é a linha entre os
+/// centros; a separação é a menor distância nela que desfaz o toque,
+/// achada por bisseção com o próprio `HitBox::intersects` (vale pra
+/// qualquer par de formas), e cada lado anda na proporção inversa da massa.
+/// O impulso só sai se os dois estão se aproximando.
+pub fn bounce(a: &Body, b: &Body, restitution: f32) -> Contact {
+    let (dx, dy) = (b.hitbox.pos.x - a.hitbox.pos.x, b.hitbox.pos.y - a.hitbox.pos.y);
+    let dist = dx.hypot(dy);
+    let (nx, ny) = if dist > 0.0 { (dx / dist, dy / dist) } else { (1.0, 0.0) };
+
+    let (inv_a, inv_b) = (1.0 / a.mass, 1.0 / b.mass);
+    let (share_a, share_b) = (inv_a / (inv_a + inv_b), inv_b / (inv_a + inv_b));
+
+    // com os círculos envolventes encostados já não há toque
+    let mut lo = 0.0;
+    let mut hi = (a.hitbox.shape.radius() + b.hitbox.shape.radius() - dist).max(0.0);
+    for _ in 0..SEPARATION_STEPS {
+        let mid = (lo + hi) / 2.0;
+        let moved_a = shifted(&a.hitbox, -nx * mid * share_a, -ny * mid * share_a);
+        let moved_b = shifted(&b.hitbox, nx * mid * share_b, ny * mid * share_b);
+        if moved_a.intersects(&moved_b) { lo = mid } else { hi = mid }
+    }
+
+    let closing = (a.velocity.vx - b.velocity.vx) * nx + (a.velocity.vy - b.velocity.vy) * ny;
+    let (mut velocity_a, mut velocity_b) = (a.velocity, b.velocity);
+    if closing > 0.0 {
+        let impulse = (1.0 + restitution) * closing / (inv_a + inv_b);
+        velocity_a.vx -= impulse * inv_a * nx;
+        velocity_a.vy -= impulse * inv_a * ny;
+        velocity_b.vx += impulse * inv_b * nx;
+        velocity_b.vy += impulse * inv_b * ny;
+    }
+
+    Contact {
+        push_a: (-nx * hi * share_a, -ny * hi * share_a),
+        push_b: (nx * hi * share_b, ny * hi * share_b),
+        velocity_a,
+        velocity_b,
+        closing: closing.max(0.0),
+    }
+}
+
+fn shifted(hitbox: &HitBox, dx: f32, dy: f32) -> HitBox {
+    HitBox { pos: Point { x: hitbox.pos.x + dx, y: hitbox.pos.y + dy }, ..*hitbox }
+}
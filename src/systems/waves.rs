@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+
+use crate::collections::asteroid_collection::AsteroidCollection;
+use crate::entities::hitbox::EntityKind;
+use crate::events::GameEvent;
+use crate::world::World;
+
+/// Curva de dificuldade das ondas. Só dados: mudar a progressão é mexer
+/// nesses números, não no `WaveDirector`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DifficultyCurve {
+    /// Asteroides grandes na primeira onda.
+    pub base_asteroids: u32,
+    /// Asteroides a mais a cada onda.
+    pub asteroids_per_wave: u32,
+    /// Asteroides a mais por jogador vivo (fracionário, arredonda pra baixo).
+    pub asteroids_per_player: f32,
+    /// Velocidade extra por onda (0.1 = +10% sobre a velocidade do tier).
+    pub speed_per_wave: f32,
+    /// Teto do multiplicador de velocidade.
+    pub max_speed_scale: f32,
+    /// Pausa entre limpar o campo e a próxima onda.
+    pub intermission_secs: f32,
+}
+
+impl Default for DifficultyCurve {
+    fn default() -> Self {
+        Self {
+            base_asteroids: 12,
+            asteroids_per_wave: 4,
+            asteroids_per_player: 0.5,
+            speed_per_wave: 0.08,
+            max_speed_scale: 2.5,
+            intermission_secs: 3.0,
+        }
+    }
+}
+
+impl DifficultyCurve {
+    /// `num_players` conta só naves em jogo (destruída esperando a volta não).
+    pub fn asteroid_count(&self, wave: u32, num_players: usize) -> u32 {
+        let by_wave = self.asteroids_per_wave * wave.saturating_sub(1);
+        let by_players = (self.asteroids_per_player * num_players as f32) as u32;
+        self.base_asteroids + by_wave + by_players
+    }
+
+    pub fn speed_scale(&self, wave: u32) -> f32 {
+        let scale = 1.0 + self.speed_per_wave * wave.saturating_sub(1) as f32;
+        scale.min(self.max_speed_scale)
+    }
+}
+
+/// Controla as ondas: a onda acaba quando o campo fica limpo e, depois
+/// do intervalo, a próxima entra maior e mais rápida.
+#[derive(Clone)]
+pub struct WaveDirector {
+    curve: DifficultyCurve,
+    tick_rate: u8,
+    wave: u32,
+    intermission: u32,
+}
+
+impl WaveDirector {
+    pub fn new(curve: DifficultyCurve, tick_rate: u8) -> Self {
+        Self { curve, tick_rate, wave: 0, intermission: 0 }
+    }
+
+    /// Curva nova vale a partir da próxima onda; a atual segue como está.
+    pub fn set_curve(&mut self, curve: DifficultyCurve) {
+        self.curve = curve;
+    }
+
+    pub fn wave(&self) -> u32 {
+        self.wave
+    }
+
+    pub fn update(&mut self, asteroids: &mut AsteroidCollection, world: &mut World, num_players: usize, events: &mut Vec<GameEvent>) {
+        if world.count(EntityKind::Asteroid) > 0 {
+            return;
+        }
+
+        if self.intermission > 0 {
+            self.intermission -= 1;
+            return;
+        }
+
+        self.start_next_wave(asteroids, world, num_players, events);
+    }
+
+    pub fn start_next_wave(&mut self, asteroids: &mut AsteroidCollection, world: &mut World, num_players: usize, events: &mut Vec<GameEvent>) {
+        self.wave += 1;
+
+        let count = (self.curve.asteroid_count(self.wave, num_players) as usize).min(asteroids.max_asteroids);
+        asteroids.speed_scale = self.curve.speed_scale(self.wave);
+
+        for _ in 0..count {
+            asteroids.random_spawn(world, events);
+        }
+
+        tracing::debug!(wave = self.wave, asteroids = count, "onda começou");
+        events.push(GameEvent::WaveStarted { wave: self.wave, asteroids: count as u32 });
+        self.intermission = (self.curve.intermission_secs * self.tick_rate as f32) as u32;
+    }
+
+    /// Volta pra onda 1 com o campo limpo (fim de rodada).
+    pub fn restart(&mut self, asteroids: &mut AsteroidCollection, world: &mut World, num_players: usize, events: &mut Vec<GameEvent>) {
+        world.clear(EntityKind::Asteroid);
+        self.wave = 0;
+        self.start_next_wave(asteroids, world, num_players, events);
+    }
+}
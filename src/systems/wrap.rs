@@ -0,0 +1,37 @@
+use crate::modes::battle_royale::SafeZone;
+use crate::world::World;
+
+/// Bordas do mundo: dão a volta ou seguram, conforme o modo. Com zona
+/// segura, quem estava dentro dela para no limite.
+pub struct WrapSystem;
+
+impl WrapSystem {
+    pub fn run(world: &mut World, wrap: bool, zone: Option<&SafeZone>) {
+        let size = world.size();
+        for (_, transform) in world.transforms.iter_mut() {
+            transform.keep_inside(size, wrap);
+        }
+        if let Some(zone) = zone {
+            Self::confine(world, zone);
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    pub fn run_parallel(world: &mut World, wrap: bool, zone: Option<&SafeZone>) {
+        use rayon::prelude::*;
+
+        let size = world.size();
+        world.transforms.par_iter_mut().for_each(|(_, transform)| transform.keep_inside(size, wrap));
+        if let Some(zone) = zone {
+            Self::confine(world, zone);
+        }
+    }
+
+    fn confine(world: &mut World, zone: &SafeZone) {
+        for (id, transform) in world.transforms.iter_mut() {
+            if let Some(velocity) = world.velocities.get_mut(id) {
+                zone.confine(transform, velocity);
+            }
+        }
+    }
+}
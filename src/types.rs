@@ -1,4 +1,6 @@
+use serde::Serialize;
 use uuid::Uuid;
+use std::fmt;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use tokio_tungstenite::{WebSocketStream, tungstenite::Message};
@@ -6,9 +8,45 @@ use tokio::net::TcpStream;
 use futures_util::stream::{SplitSink, SplitStream};
 
 pub type ClientId = Uuid;
+pub type TeamId = u8;
 pub type WSStream = WebSocketStream<TcpStream>;
 pub type ArcWriter = Arc<Mutex<SplitSink<WSStream, Message>>>;
 pub type ArcReader = Arc<Mutex<SplitStream<WSStream>>>;
 
-pub const TICK_RATE: u8 = 32;
-pub const WORLD_SIZE: u32 = 6000;
\ No newline at end of file
+/// Id de entidade do mundo (asteroide, bala), dado pelo `SlotMap` da
+/// coleção: índice do slot nos bits de baixo, geração nos de cima. O slot
+/// reaproveitado ganha geração nova, então um id antigo não acha quem
+/// ocupou o lugar. Vai pro cliente como número (`serde(transparent)`).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default, Serialize)]
+#[serde(transparent)]
+pub struct EntityId(u32);
+
+impl EntityId {
+    /// Bits do índice: até ~1M entidades vivas por coleção.
+    pub const INDEX_BITS: u32 = 20;
+    pub const MAX_INDEX: u32 = (1 << Self::INDEX_BITS) - 1;
+    /// A geração dá a volta depois de 4096 reusos do mesmo slot.
+    pub const MAX_GENERATION: u32 = u32::MAX >> Self::INDEX_BITS;
+
+    pub fn new(index: u32, generation: u32) -> EntityId {
+        EntityId((generation & Self::MAX_GENERATION) << Self::INDEX_BITS | (index & Self::MAX_INDEX))
+    }
+
+    pub fn index(self) -> usize {
+        (self.0 & Self::MAX_INDEX) as usize
+    }
+
+    pub fn generation(self) -> u32 {
+        self.0 >> Self::INDEX_BITS
+    }
+
+    pub fn to_bits(self) -> u32 {
+        self.0
+    }
+}
+
+impl fmt::Display for EntityId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
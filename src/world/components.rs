@@ -0,0 +1,131 @@
+use crate::entities::hitbox::{BodyId, EntityKind, HitBox, Point, Shape};
+use crate::entities::polygon::Polygon;
+use crate::entities::traits::warp_object::WarpObject;
+use crate::entities::weapon::WeaponKind;
+use crate::types::{ClientId, TeamId};
+
+/// Posição e rumo (pra onde a entidade aponta).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Transform {
+    pub x: f32,
+    pub y: f32,
+    pub angle: f32,
+}
+
+impl Transform {
+    pub fn new(x: f32, y: f32, angle: f32) -> Transform {
+        Transform { x, y, angle }
+    }
+
+    pub fn advance(&mut self, velocity: &Velocity) {
+        self.x += velocity.vx;
+        self.y += velocity.vy;
+    }
+
+    /// Gira o rumo, mantendo o ângulo em [0, 2π).
+    pub fn rotate(&mut self, spin: &Spin) {
+        self.angle = (self.angle + spin.rate).rem_euclid(std::f32::consts::TAU);
+    }
+
+    /// Dá a volta no mundo (`wrap`) ou prende nas bordas.
+    pub fn keep_inside(&mut self, world_size: f32, wrap: bool) {
+        (self.x, self.y) = if wrap { self.warp(world_size) } else { self.clamp(world_size) };
+    }
+}
+
+impl WarpObject for Transform {
+    fn position(&self) -> (f32, f32) {
+        (self.x, self.y)
+    }
+}
+
+/// Deslocamento por tick.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Velocity {
+    pub vx: f32,
+    pub vy: f32,
+}
+
+impl Velocity {
+    pub fn from_angle(speed: f32, angle: f32) -> Velocity {
+        Velocity { vx: speed * f32::cos(angle), vy: speed * f32::sin(angle) }
+    }
+
+    pub fn speed(&self) -> f32 {
+        (self.vx * self.vx + self.vy * self.vy).sqrt()
+    }
+}
+
+/// Giro por tick (radianos), somado ao ângulo do `Transform`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Spin {
+    pub rate: f32,
+}
+
+/// Ticks até a entidade sumir sozinha.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lifetime {
+    pub ticks: u32,
+}
+
+/// Forma e layers de colisão; a posição vem do `Transform`.
+#[derive(Clone, Copy)]
+pub struct Collider {
+    pub kind: EntityKind,
+    pub shape: Shape,
+    pub mask: u8,
+}
+
+impl Collider {
+    pub fn circle(kind: EntityKind, radius: f32, mask: u8) -> Collider {
+        Collider { kind, shape: Shape::Circle { radius }, mask }
+    }
+
+    pub fn polygon(kind: EntityKind, polygon: Polygon, mask: u8) -> Collider {
+        Collider { kind, shape: Shape::Polygon(polygon), mask }
+    }
+
+    /// Raio que envolve a forma.
+    pub fn radius(&self) -> f32 {
+        self.shape.radius()
+    }
+
+    /// Polígono já girado pelo ângulo do `Transform`.
+    pub fn hitbox(&self, id: impl Into<BodyId>, transform: &Transform, team: Option<TeamId>) -> HitBox {
+        let shape = match self.shape {
+            Shape::Polygon(polygon) => Shape::Polygon(polygon.rotated(transform.angle)),
+            circle => circle,
+        };
+        HitBox {
+            id: id.into(),
+            kind: self.kind,
+            pos: Point { x: transform.x, y: transform.y },
+            shape,
+            mask: self.mask,
+            team,
+        }
+    }
+}
+
+/// Jogador responsável pela entidade (pontos, fogo amigo).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Owner {
+    pub player: ClientId,
+    pub team: Option<TeamId>,
+}
+
+/// Arma que disparou o projétil e o dano que ele leva. `hostile` marca o
+/// tiro de disco voador: acerta nave como `Damage::Ufo` e não fere disco.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Projectile {
+    pub weapon: WeaponKind,
+    pub damage: f32,
+    pub hostile: bool,
+}
+
+/// Vira atrás da nave inimiga mais perto, no máximo `turn_rate` radianos
+/// por tick, sem mudar a velocidade escalar.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Homing {
+    pub turn_rate: f32,
+}
@@ -0,0 +1,132 @@
+pub mod components;
+pub mod storage;
+
+use crate::collections::slot_map::SlotMap;
+use crate::config::GameConfig;
+use crate::entities::asteroid::AsteroidType;
+use crate::entities::hitbox::{EntityKind, HitBox};
+use crate::entities::powerup::PowerUpKind;
+use crate::entities::ufo::Ufo;
+use crate::types::EntityId;
+
+use components::{Collider, Homing, Lifetime, Owner, Projectile, Spin, Transform, Velocity};
+use storage::Storage;
+
+/// Entidades do mundo que não são jogadores (asteroides, balas) guardadas
+/// como componentes. Os sistemas de `crate::systems` rodam por cima deles
+/// em ordem fixa a cada tick; tipo novo de entidade é uma combinação de
+/// componentes, e componente novo é mais um `Storage` aqui (e em `despawn`).
+#[derive(Clone)]
+pub struct World {
+    entities: SlotMap<EntityKind>,
+    pub transforms: Storage<Transform>,
+    pub velocities: Storage<Velocity>,
+    pub spins: Storage<Spin>,
+    pub lifetimes: Storage<Lifetime>,
+    pub colliders: Storage<Collider>,
+    pub owners: Storage<Owner>,
+    pub projectiles: Storage<Projectile>,
+    pub homing: Storage<Homing>,
+    pub tiers: Storage<AsteroidType>,
+    pub powerups: Storage<PowerUpKind>,
+    pub ufos: Storage<Ufo>,
+    size: f32,
+}
+
+impl Default for World {
+    fn default() -> Self {
+        Self::with_config(&GameConfig::default())
+    }
+}
+
+impl World {
+    pub fn with_config(config: &GameConfig) -> World {
+        World {
+            entities: SlotMap::new(),
+            transforms: Storage::new(),
+            velocities: Storage::new(),
+            spins: Storage::new(),
+            lifetimes: Storage::new(),
+            colliders: Storage::new(),
+            owners: Storage::new(),
+            projectiles: Storage::new(),
+            homing: Storage::new(),
+            tiers: Storage::new(),
+            powerups: Storage::new(),
+            ufos: Storage::new(),
+            size: config.world_size as f32,
+        }
+    }
+
+    pub fn size(&self) -> f32 {
+        self.size
+    }
+
+    /// Entidade nova, ainda sem componentes.
+    pub fn spawn(&mut self, kind: EntityKind) -> EntityId {
+        self.entities.insert(kind)
+    }
+
+    pub fn despawn(&mut self, id: EntityId) -> bool {
+        if self.entities.remove(id).is_none() {
+            return false;
+        }
+
+        self.transforms.remove(id);
+        self.velocities.remove(id);
+        self.spins.remove(id);
+        self.lifetimes.remove(id);
+        self.colliders.remove(id);
+        self.owners.remove(id);
+        self.projectiles.remove(id);
+        self.homing.remove(id);
+        self.tiers.remove(id);
+        self.powerups.remove(id);
+        self.ufos.remove(id);
+        true
+    }
+
+    pub fn contains(&self, id: EntityId) -> bool {
+        self.entities.contains(id)
+    }
+
+    pub fn kind(&self, id: EntityId) -> Option<EntityKind> {
+        self.entities.get(id).copied()
+    }
+
+    /// Ids de um tipo, na ordem dos slots.
+    pub fn ids(&self, kind: EntityKind) -> impl Iterator<Item = EntityId> + '_ {
+        self.entities.iter().filter(move |(_, k)| **k == kind).map(|(id, _)| id)
+    }
+
+    pub fn count(&self, kind: EntityKind) -> usize {
+        self.entities.values().filter(|k| **k == kind).count()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    pub fn clear(&mut self, kind: EntityKind) {
+        let ids: Vec<EntityId> = self.ids(kind).collect();
+        for id in ids {
+            self.despawn(id);
+        }
+    }
+
+    /// Caixas de colisão de quem tem `Collider` e `Transform`.
+    pub fn hitboxes(&self) -> Vec<HitBox> {
+        self.colliders
+            .iter()
+            .filter_map(|(id, collider)| {
+                let transform = self.transforms.get(id)?;
+                let team = self.owners.get(id).and_then(|owner| owner.team);
+                Some(collider.hitbox(id, transform, team))
+            })
+            .collect()
+    }
+}
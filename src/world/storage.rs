@@ -0,0 +1,82 @@
+use crate::types::EntityId;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Slots por tarefa no pool: abaixo disso dividir custa mais que ajuda.
+#[cfg(feature = "parallel")]
+pub const PAR_MIN_LEN: usize = 256;
+
+/// Componentes de um tipo, indexados pelo slot do `EntityId`. Cada slot
+/// guarda o id completo, então um id de geração antiga não acha nada.
+#[derive(Clone)]
+pub struct Storage<T> {
+    items: Vec<Option<(EntityId, T)>>,
+}
+
+impl<T> Default for Storage<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Storage<T> {
+    pub fn new() -> Storage<T> {
+        Storage { items: Vec::new() }
+    }
+
+    pub fn insert(&mut self, id: EntityId, value: T) {
+        let index = id.index();
+        if index >= self.items.len() {
+            self.items.resize_with(index + 1, || None);
+        }
+        self.items[index] = Some((id, value));
+    }
+
+    pub fn get(&self, id: EntityId) -> Option<&T> {
+        match self.items.get(id.index()) {
+            Some(Some((owner, value))) if *owner == id => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, id: EntityId) -> Option<&mut T> {
+        match self.items.get_mut(id.index()) {
+            Some(Some((owner, value))) if *owner == id => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn contains(&self, id: EntityId) -> bool {
+        self.get(id).is_some()
+    }
+
+    pub fn remove(&mut self, id: EntityId) -> Option<T> {
+        let item = self.items.get_mut(id.index())?;
+        match item {
+            Some((owner, _)) if *owner == id => item.take().map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// Na ordem dos slots.
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, &T)> {
+        self.items.iter().filter_map(|item| item.as_ref().map(|(id, value)| (*id, value)))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (EntityId, &mut T)> {
+        self.items.iter_mut().filter_map(|item| item.as_mut().map(|(id, value)| (*id, value)))
+    }
+
+    /// Como `iter_mut`, dividido entre as threads do pool.
+    #[cfg(feature = "parallel")]
+    pub fn par_iter_mut(&mut self) -> impl ParallelIterator<Item = (EntityId, &mut T)>
+    where
+        T: Send,
+    {
+        self.items
+            .par_iter_mut()
+            .with_min_len(PAR_MIN_LEN)
+            .filter_map(|item| item.as_mut().map(|(id, value)| (*id, value)))
+    }
+}
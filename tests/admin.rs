@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use asteroids_server::config::AdminConfig;
+use asteroids_server::entities::asteroid::AsteroidType;
+use asteroids_server::entities::hitbox::EntityKind;
+use asteroids_server::game::GameManager;
+use asteroids_server::networking::admin::{self, Admin, AdminCommand};
+use asteroids_server::networking::router::Router;
+use asteroids_server::networking::websocket_handler::{WebSocketHandler, forwarded_ip};
+use axum::extract::ws::Message;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use tokio::sync::Mutex;
+use tower::ServiceExt;
+
+fn admin(game: Arc<Mutex<GameManager>>) -> Admin {
+    let server = Arc::new(WebSocketHandler::new(Router::new(game)));
+    let config = AdminConfig { secret: "s3nha".into(), audit_log: String::new() };
+    Admin::new(server, &config)
+}
+
+fn post(token: &str, body: &str) -> Request<Body> {
+    Request::post("/admin")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn requests_without_the_secret_are_refused() {
+    let game = Arc::new(Mutex::new(GameManager::with_seed(1)));
+    let app = admin::routes(Arc::new(admin(game.clone())));
+
+    let reply = app.clone().oneshot(post("errada", r#"{"action":"pause"}"#)).await.unwrap();
+    assert_eq!(reply.status(), StatusCode::UNAUTHORIZED);
+    assert!(!game.lock().await.is_paused());
+
+    let reply = app.oneshot(post("s3nha", r#"{"action":"pause"}"#)).await.unwrap();
+    assert_eq!(reply.status(), StatusCode::OK);
+    assert!(game.lock().await.is_paused());
+}
+
+#[tokio::test]
+async fn pause_spawn_and_clear_the_field() {
+    let game = Arc::new(Mutex::new(GameManager::with_seed(1)));
+    let admin = admin(game.clone());
+
+    admin.execute(&AdminCommand::Pause).await.unwrap();
+    let before = game.lock().await.world.count(EntityKind::Asteroid);
+    game.lock().await.tick();
+    assert_eq!(game.lock().await.world.count(EntityKind::Asteroid), before);
+
+    admin.execute(&AdminCommand::ClearField).await.unwrap();
+    assert!(game.lock().await.world.count(EntityKind::Asteroid) == 0);
+
+    let spawn = AdminCommand::SpawnAsteroid { x: 100.0, y: 200.0, tier: AsteroidType::MEDIUM };
+    admin.execute(&spawn).await.unwrap();
+    assert_eq!(game.lock().await.world.count(EntityKind::Asteroid), 1);
+
+    admin.execute(&AdminCommand::Resume).await.unwrap();
+    assert!(!game.lock().await.is_paused());
+
+    // sem sessão não há o que derrubar
+    assert!(admin.execute(&AdminCommand::Kick { id: uuid::Uuid::new_v4() }).await.is_err());
+    assert!(admin.execute(&AdminCommand::Ban { id: None, ip: None }).await.is_err());
+}
+
+#[test]
+fn empty_secret_disables_the_channel() {
+    let game = Arc::new(Mutex::new(GameManager::with_seed(1)));
+    let server = Arc::new(WebSocketHandler::new(Router::new(game)));
+    let admin = Admin::new(server, &AdminConfig::default());
+
+    assert!(!admin.authorize(Some("")));
+    assert!(!admin.authorize(None));
+}
+
+#[test]
+fn forwarded_address_only_counts_behind_a_trusted_proxy() {
+    let proxy = "10.0.0.1".parse().unwrap();
+    let header = Some("6.6.6.6, 203.0.113.7");
+
+    // sem proxy configurado o cabeçalho é do cliente, não vale
+    assert_eq!(forwarded_ip(proxy, header, 0), proxy);
+    // o proxy anotou o último; o primeiro pode ser inventado
+    assert_eq!(forwarded_ip(proxy, header, 1), "203.0.113.7".parse::<std::net::IpAddr>().unwrap());
+    assert_eq!(forwarded_ip(proxy, None, 1), proxy);
+    assert_eq!(forwarded_ip(proxy, Some("lixo"), 1), proxy);
+}
+
+#[tokio::test]
+async fn messages_from_a_dropped_session_are_ignored() {
+    let game = Arc::new(Mutex::new(GameManager::with_seed(1)));
+    let server = WebSocketHandler::new(Router::new(game.clone()));
+
+    // sessão que não existe mais (kick, ban, afk) não volta a jogar
+    server.on_message(&uuid::Uuid::new_v4(), Message::Text(r#"{"action":"connect"}"#.into())).await;
+    assert!(game.lock().await.players.is_empty());
+}
@@ -0,0 +1,59 @@
+use asteroids_server::config::{AfkAction, AfkConfig, GameConfig};
+use asteroids_server::events::GameEvent;
+use asteroids_server::game::GameManager;
+use asteroids_server::networking::router::MovePayload;
+use uuid::Uuid;
+
+fn game(action: AfkAction) -> GameManager {
+    let config = GameConfig {
+        tick_rate: 10,
+        afk: AfkConfig { idle_secs: 2.0, warn_secs: 1.0, action },
+        ..GameConfig::default()
+    };
+    GameManager::with_config(config, Some(5))
+}
+
+fn run(game: &mut GameManager, ticks: u32) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    for _ in 0..ticks {
+        game.tick();
+        events.extend(game.drain_events());
+    }
+    events
+}
+
+#[test]
+fn idle_player_is_warned_then_moved_to_spectator() {
+    let mut game = game(AfkAction::Spectate);
+    let id = Uuid::from_u128(7);
+    game.add_player(&id).unwrap();
+
+    let events = run(&mut game, 10);
+    assert!(events.contains(&GameEvent::IdleWarning { id, remove_in: 1.0 }));
+    assert_eq!(game.players.len(), 1);
+
+    let events = run(&mut game, 10);
+    assert!(events.contains(&GameEvent::PlayerIdle { id, action: AfkAction::Spectate }));
+    assert!(events.contains(&GameEvent::PlayerLeft { id }));
+    assert!(game.players.is_empty());
+    // espectador: a sessão continua, nada pra derrubar
+    assert!(game.drain_idle_kicks().is_empty());
+}
+
+#[test]
+fn input_resets_the_idle_timer() {
+    let mut game = game(AfkAction::Disconnect);
+    let id = Uuid::from_u128(7);
+    game.add_player(&id).unwrap();
+
+    let turn = MovePayload { thrust: false, left: true, right: false, fire: false };
+    for _ in 0..30 {
+        game.handle_player_command(&id, &turn);
+        game.tick();
+    }
+    assert_eq!(game.players.len(), 1);
+
+    run(&mut game, 20);
+    assert!(game.players.is_empty());
+    assert_eq!(game.drain_idle_kicks(), vec![id]);
+}
@@ -1,2 +1,6 @@
 #[path = "collections/bullet_collection.rs"]
 mod bullet_collection;
+#[path = "collections/slot_map.rs"]
+mod slot_map;
+#[path = "collections/asteroid_collection.rs"]
+mod asteroid_collection;
@@ -0,0 +1,57 @@
+use asteroids_server::collections::asteroid_collection::AsteroidCollection;
+use asteroids_server::entities::asteroid::AsteroidType;
+use asteroids_server::entities::hitbox::EntityKind;
+use asteroids_server::systems::movement::MovementSystem;
+use asteroids_server::world::World;
+use asteroids_server::world::components::Velocity;
+use std::f32::consts::FRAC_PI_2;
+
+#[test]
+fn split_children_inherit_momentum_and_spread_from_the_impact() {
+    let mut world = World::default();
+    let mut asteroids = AsteroidCollection::seeded(7);
+    let mut events = Vec::new();
+
+    asteroids.spawn(&mut world, 1000.0, 1000.0, AsteroidType::BIG, &mut events);
+    let parent = world.ids(EntityKind::Asteroid).next().unwrap();
+    world.velocities.insert(parent, Velocity { vx: 3.0, vy: 0.0 });
+
+    // bala descendo (+y)
+    assert!(asteroids.remove_by_id(&mut world, parent, None, Some(FRAC_PI_2), &mut events));
+
+    let children: Vec<_> = world.ids(EntityKind::Asteroid).collect();
+    assert_eq!(children.len(), 2);
+
+    let mut sides = Vec::new();
+    for &id in &children {
+        // tira a herança do pai: sobra a velocidade do tier, aberta em volta do impacto
+        let v = world.velocities.get(id).unwrap();
+        let own = Velocity { vx: v.vx - 3.0, vy: v.vy };
+        assert!((own.speed() - 4.0).abs() < 1e-3);
+
+        let offset = own.vy.atan2(own.vx) - FRAC_PI_2;
+        assert!((0.35..0.9).contains(&offset.abs()), "abertura {}", offset);
+        sides.push(offset.signum());
+    }
+    assert_ne!(sides[0], sides[1]);
+
+    // lado a lado, não empilhados
+    let (a, b) = (world.transforms.get(children[0]).unwrap(), world.transforms.get(children[1]).unwrap());
+    assert!((a.x - b.x).hypot(a.y - b.y) > 30.0);
+}
+
+#[test]
+fn asteroids_spin_every_tick() {
+    let mut world = World::default();
+    let mut asteroids = AsteroidCollection::seeded(3);
+    asteroids.spawn(&mut world, 500.0, 500.0, AsteroidType::SMALL, &mut Vec::new());
+    let id = world.ids(EntityKind::Asteroid).next().unwrap();
+
+    let rate = world.spins.get(id).unwrap().rate;
+    assert!(rate != 0.0 && rate.abs() < 0.05);
+
+    MovementSystem::run(&mut world);
+    MovementSystem::run(&mut world);
+    let angle = world.transforms.get(id).unwrap().angle;
+    assert!((angle - (2.0 * rate).rem_euclid(std::f32::consts::TAU)).abs() < 1e-4);
+}
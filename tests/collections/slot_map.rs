@@ -0,0 +1,32 @@
+use asteroids_server::collections::slot_map::SlotMap;
+
+#[test]
+fn removed_id_does_not_find_the_slot_reuser() {
+    let mut map = SlotMap::new();
+    let old = map.insert("velho");
+    assert_eq!(map.remove(old), Some("velho"));
+
+    // mesmo slot, geração nova
+    let new = map.insert("novo");
+    assert_eq!(new.index(), old.index());
+    assert_ne!(new, old);
+
+    assert_eq!(map.get(old), None);
+    assert_eq!(map.remove(old), None);
+    assert_eq!(map.get(new), Some(&"novo"));
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn ids_stay_small_and_iteration_follows_slots() {
+    let mut map = SlotMap::new();
+    let ids: Vec<_> = (0..4).map(|i| map.insert(i)).collect();
+    assert_eq!(ids.iter().map(|id| id.to_bits()).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+
+    map.retain(|_, v| *v % 2 == 0);
+    assert_eq!(map.values().copied().collect::<Vec<_>>(), vec![0, 2]);
+
+    map.clear();
+    assert!(map.is_empty());
+    assert!(ids.iter().all(|id| !map.contains(*id)));
+}
@@ -0,0 +1,104 @@
+use asteroids_server::config::GameConfig;
+use asteroids_server::game::GameManager;
+
+fn pairs(items: &[(&str, &str)]) -> Vec<(String, String)> {
+    items.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+#[test]
+fn partial_file_keeps_defaults_for_the_rest() {
+    let config = GameConfig::from_toml(
+        r#"
+        tick_rate = 60
+
+        [ship]
+        acceleration = 9.5
+
+        [mode]
+        name = "survival"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(config.tick_rate, 60);
+    assert_eq!(config.ship.acceleration, 9.5);
+    assert_eq!(config.mode.name, "survival");
+    assert_eq!(config.world_size, GameConfig::default().world_size);
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn unknown_field_in_file_is_an_error() {
+    assert!(GameConfig::from_toml("tick_rat = 60").is_err());
+}
+
+#[test]
+fn overrides_parse_values_and_reject_unknown_keys() {
+    let config = GameConfig::default()
+        .with_overrides(&pairs(&[("world_size", "8000"), ("ship.max_speed", "12.5"), ("mode.name", "time_attack")]))
+        .unwrap();
+
+    assert_eq!(config.world_size, 8000);
+    assert_eq!(config.ship.max_speed, 12.5);
+    assert_eq!(config.mode.name, "time_attack");
+
+    assert!(GameConfig::default().with_overrides(&pairs(&[("ship.warp_drive", "1")])).is_err());
+    assert!(GameConfig::default().with_overrides(&pairs(&[("tick_rate", "fast")])).is_err());
+}
+
+#[test]
+fn validate_reports_every_problem() {
+    let mut config = GameConfig { tick_rate: 0, ..GameConfig::default() };
+    config.mode.name = "capture_the_flag".into();
+    config.ship.acceleration = -1.0;
+    config.mode.score_limit = 0;
+    config.mode.time_limit_secs = 0;
+
+    let err = config.validate().unwrap_err();
+    assert!(err.contains("tick_rate"));
+    assert!(err.contains("capture_the_flag"));
+    assert!(err.contains("ship.acceleration"));
+    assert!(err.contains("mode.score_limit"));
+    assert!(err.contains("mode.time_limit_secs"));
+}
+
+#[test]
+fn nan_and_infinity_are_rejected() {
+    for raw in ["nan", "inf"] {
+        let config = GameConfig::default()
+            .with_overrides(&pairs(&[("heartbeat.interval_secs", raw), ("waves.intermission_secs", raw)]))
+            .unwrap();
+        let err = config.validate().unwrap_err();
+        assert!(err.contains("heartbeat.interval_secs"), "{}", err);
+        assert!(err.contains("waves.intermission_secs"), "{}", err);
+    }
+}
+
+#[test]
+fn unknown_env_keys_are_set_aside() {
+    let (known, ignored) = GameConfig::default()
+        .known_overrides(pairs(&[("ship.max_speed", "9"), ("log_dir", "/tmp"), ("ship", "1")]));
+    assert_eq!(known, pairs(&[("ship.max_speed", "9")]));
+    assert_eq!(ignored, vec!["log_dir".to_string(), "ship".to_string()]);
+}
+
+#[test]
+fn game_uses_config_limits() {
+    let config = GameConfig { max_players: 1, ..GameConfig::default() };
+
+    let mut game = GameManager::with_config(config, Some(1));
+    assert!(game.add_player(&uuid::Uuid::new_v4()).is_ok());
+    assert!(game.add_player(&uuid::Uuid::new_v4()).is_err());
+}
+
+#[test]
+fn log_section_accepts_json_and_rejects_bad_filter() {
+    let config = GameConfig::from_toml("[log]\nlevel = \"asteroids_server=debug,warn\"\nformat = \"json\"").unwrap();
+    assert_eq!(config.log.format, asteroids_server::config::LogFormat::Json);
+    assert!(config.validate().is_ok());
+
+    assert!(GameConfig::from_toml("[log]\nformat = \"xml\"").is_err());
+
+    let config = GameConfig::default().with_overrides(&pairs(&[("log.level", "=[")])).unwrap();
+    assert!(config.validate().unwrap_err().contains("log.level"));
+}
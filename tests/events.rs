@@ -0,0 +1,97 @@
+use std::f32::consts::FRAC_PI_2;
+
+use asteroids_server::collections::asteroid_collection::AsteroidCollection;
+use asteroids_server::collections::bullet_collection::BulletCollection;
+use asteroids_server::collections::player_collection::PlayerCollection;
+use asteroids_server::entities::asteroid::AsteroidType;
+use asteroids_server::entities::bullet::Bullet;
+use asteroids_server::entities::hitbox::EntityKind;
+use asteroids_server::events::GameEvent;
+use asteroids_server::game::GameManager;
+use asteroids_server::systems::collision::CollisionSystem;
+use asteroids_server::world::World;
+use uuid::Uuid;
+
+#[test]
+fn bullet_on_asteroid_emits_destroy_and_split() {
+    let mut players = PlayerCollection::seeded(1);
+    let bullets = BulletCollection::new();
+    let mut world = World::default();
+    let mut asteroids = AsteroidCollection::seeded(1);
+    let mut events = Vec::new();
+
+    asteroids.spawn(&mut world, 100.0, 100.0, AsteroidType::BIG, &mut events);
+    events.clear();
+
+    let shooter = Uuid::new_v4();
+    bullets.add_bullet(&mut world, Bullet::new(shooter, 100.0, 100.0, 0.0, 0.0, 160));
+
+    CollisionSystem::run(&mut world, &mut players, &mut asteroids, true, None, &mut events);
+
+    let destroyed: Vec<_> = events
+        .iter()
+        .filter_map(|e| match e {
+            GameEvent::AsteroidDestroyed { id, tier, by } => Some((*id, *tier, *by)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(destroyed.len(), 1);
+    assert_eq!(destroyed[0].1, AsteroidType::BIG);
+    assert_eq!(destroyed[0].2, Some(shooter));
+
+    // os dois filhos apontam pro pai destruído
+    let children = events
+        .iter()
+        .filter(|e| matches!(
+            e,
+            GameEvent::AsteroidSpawned { tier: AsteroidType::MEDIUM, parent: Some(p), .. } if *p == destroyed[0].0
+        ))
+        .count();
+    assert_eq!(children, 2);
+}
+
+#[test]
+fn split_near_the_edge_keeps_children_apart_and_unwrapped() {
+    let mut world = World::default();
+    let mut asteroids = AsteroidCollection::seeded(1);
+    let mut events = Vec::new();
+    asteroids.spawn(&mut world, 5.0, 100.0, AsteroidType::BIG, &mut events);
+    let parent = world.ids(EntityKind::Asteroid).next().unwrap();
+    events.clear();
+
+    // impacto em +y: os filhos abrem pros lados em x, um deles pra fora da borda
+    asteroids.remove_by_id(&mut world, parent, None, Some(FRAC_PI_2), &mut events);
+    let children: Vec<(f32, f32, f32)> = world
+        .ids(EntityKind::Asteroid)
+        .map(|id| {
+            let transform = world.transforms.get(id).unwrap();
+            (transform.x, transform.y, world.colliders.get(id).unwrap().radius())
+        })
+        .collect();
+    assert_eq!(children.len(), 2);
+
+    // quem dá a volta (ou prende, sem wrap) é o WrapSystem, não o split
+    assert!(children.iter().all(|c| c.0 < 100.0));
+    assert!(children.iter().any(|c| c.0 < 0.0));
+    let [(ax, ay, ar), (bx, by, br)] = [children[0], children[1]];
+    assert!((ax - bx).hypot(ay - by) > ar + br);
+}
+
+#[test]
+fn join_and_leave_are_reported_once_in_snapshot() {
+    let mut game = GameManager::with_seed(7);
+    game.drain_events();
+
+    let id = Uuid::new_v4();
+    game.add_player(&id).unwrap();
+    game.remove_player(&id);
+
+    let events = game.drain_events();
+    assert!(matches!(events[0], GameEvent::PlayerSpawned { id: p, .. } if p == id));
+    assert!(matches!(events[1], GameEvent::PlayerLeft { id: p } if p == id));
+
+    // o snapshot esvazia a fila: o segundo não repete os eventos
+    game.add_player(&id).unwrap();
+    assert!(game.get_game_state().contains("\"type\":\"player_spawned\""));
+    assert!(game.get_game_state().contains("\"Events\":[]"));
+}
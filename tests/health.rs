@@ -0,0 +1,119 @@
+use asteroids_server::collections::asteroid_collection::AsteroidCollection;
+use asteroids_server::collections::player_collection::PlayerCollection;
+use asteroids_server::collections::ufo_collection::UfoCollection;
+use asteroids_server::config::GameConfig;
+use asteroids_server::entities::asteroid::AsteroidType;
+use asteroids_server::entities::hitbox::EntityKind;
+use asteroids_server::entities::player::Damage;
+use asteroids_server::events::{DeathCause, GameEvent};
+use asteroids_server::systems::collision::CollisionSystem;
+use asteroids_server::world::World;
+use uuid::Uuid;
+
+const SHOOTER: Uuid = Uuid::from_u128(1);
+const OTHER: Uuid = Uuid::from_u128(2);
+const VICTIM: Uuid = Uuid::from_u128(3);
+
+fn players(config: &GameConfig, respawn: bool) -> PlayerCollection {
+    let mut players = PlayerCollection::with_config(config, Some(5));
+    players.set_respawn(respawn);
+    for id in [SHOOTER, OTHER, VICTIM] {
+        players.add_player(&id).unwrap();
+    }
+    players
+}
+
+#[test]
+fn shield_soaks_damage_first_and_recharges_after_a_pause() {
+    let mut config = GameConfig::default();
+    config.ship.shield = 50.0;
+    config.ship.shield_regen = 32.0; // 1 por tick
+    config.ship.shield_delay_secs = 0.5; // 16 ticks
+    let mut players = players(&config, false);
+
+    assert_eq!(players.damage(&VICTIM, Damage::Bullet(Some(SHOOTER), 70.0)), None);
+    let victim = players.get_player(&VICTIM).unwrap();
+    assert_eq!((victim.shield(), victim.health()), (0.0, 80.0));
+
+    for _ in 0..16 {
+        players.update(true);
+    }
+    assert_eq!(players.get_player(&VICTIM).unwrap().shield(), 0.0);
+    for _ in 0..10 {
+        players.update(true);
+    }
+    assert_eq!(players.get_player(&VICTIM).unwrap().shield(), 10.0);
+}
+
+#[test]
+fn killer_is_whoever_dealt_the_last_damage() {
+    let mut config = GameConfig::default();
+    config.ship.health = 150.0;
+    let mut players = players(&config, false);
+
+    // bala de um, bala de outro: quem fechou leva
+    players.damage(&VICTIM, Damage::Bullet(Some(SHOOTER), 100.0));
+    let killed = players.damage(&VICTIM, Damage::Bullet(Some(OTHER), 100.0));
+    assert_eq!(killed, Some(GameEvent::PlayerKilled { victim: VICTIM, killer: Some(OTHER), cause: DeathCause::Bullet }));
+    assert!(players.get_player(&VICTIM).is_none());
+
+    // a última fonte foi o asteroide: ninguém leva
+    players.damage(&SHOOTER, Damage::Bullet(Some(OTHER), 100.0));
+    let killed = players.damage(&SHOOTER, Damage::Asteroid(AsteroidType::SMALL));
+    assert_eq!(killed, Some(GameEvent::PlayerKilled { victim: SHOOTER, killer: None, cause: DeathCause::Asteroid }));
+}
+
+#[test]
+fn destroyed_ship_waits_then_respawns_with_its_score() {
+    let config = GameConfig::default();
+    let delay = config.ticks(config.ship.respawn_secs);
+    let mut players = players(&config, true);
+    players.add_score(&VICTIM, 300);
+
+    assert!(players.damage(&VICTIM, Damage::Bullet(Some(SHOOTER), 100.0)).is_some());
+    let victim = players.get_player(&VICTIM).unwrap();
+    assert!(victim.is_destroyed());
+    assert_eq!(players.get_hitboxes().len(), 2);
+    // destruída não apanha de novo
+    assert_eq!(players.damage(&VICTIM, Damage::Bullet(Some(OTHER), 100.0)), None);
+
+    for _ in 1..delay {
+        players.update(true);
+        assert!(players.respawn_ready().is_empty());
+    }
+    players.update(true);
+    let spawned = players.respawn_ready();
+
+    let victim = players.get_player(&VICTIM).unwrap();
+    assert!(matches!(spawned[..], [GameEvent::PlayerSpawned { id: VICTIM, .. }]));
+    assert!(!victim.is_destroyed());
+    assert_eq!((victim.health(), victim.score()), (config.ship.health, 300));
+    assert!(victim.to_json().contains("\"health\": 100"));
+}
+
+#[test]
+fn ship_destroyed_earlier_in_the_tick_rams_nothing_else() {
+    let mut config = GameConfig::default();
+    config.ship.health = 1.0;
+    config.ship.shield = 0.0;
+    let mut players = players(&config, true);
+    for id in [SHOOTER, OTHER] {
+        players.rm_player(&id);
+    }
+    players.place(&VICTIM, 1000.0, 1000.0);
+    let mut world = World::default();
+    let mut asteroids = AsteroidCollection::seeded(1);
+    let mut ufos = UfoCollection::with_config(&config, Some(2));
+    let mut events = Vec::new();
+    asteroids.spawn(&mut world, 1000.0, 1000.0, AsteroidType::SMALL, &mut events);
+    asteroids.spawn(&mut world, 1005.0, 1000.0, AsteroidType::SMALL, &mut events);
+    ufos.spawn(&mut world, 1000.0, 1005.0, &mut events);
+    events.clear();
+
+    // o primeiro asteroide mata; o resto segue inteiro e sem crédito
+    CollisionSystem::run(&mut world, &mut players, &mut asteroids, true, None, &mut events);
+    assert!(players.get_player(&VICTIM).unwrap().is_destroyed());
+    assert_eq!(world.count(EntityKind::Asteroid), 1);
+    assert_eq!(world.count(EntityKind::Ufo), 1);
+    assert_eq!(events.iter().filter(|e| matches!(e, GameEvent::AsteroidDestroyed { .. })).count(), 1);
+}
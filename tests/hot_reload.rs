@@ -0,0 +1,67 @@
+use asteroids_server::config::GameConfig;
+use asteroids_server::entities::traits::warp_object::WarpObject;
+use asteroids_server::events::GameEvent;
+use asteroids_server::game::GameManager;
+use asteroids_server::networking::router::MovePayload;
+use uuid::Uuid;
+
+fn thrust() -> MovePayload {
+    MovePayload { thrust: true, left: false, right: false, fire: false }
+}
+
+#[test]
+fn tuning_applies_on_next_tick_to_live_ships() {
+    let id = Uuid::from_u128(1);
+    let mut normal = GameManager::with_seed(3);
+    let mut tuned = GameManager::with_seed(3);
+    normal.add_player(&id).unwrap();
+    tuned.add_player(&id).unwrap();
+
+    let mut config = tuned.config().clone();
+    config.ship.acceleration *= 3.0;
+    config.max_asteroids = 8;
+    let changed = tuned.reload_config(config).unwrap();
+    assert_eq!(changed, vec!["max_asteroids".to_string(), "ship.acceleration".to_string()]);
+
+    // ainda não aplicou: só no próximo tick
+    assert_eq!(tuned.config().ship.acceleration, GameConfig::default().ship.acceleration);
+
+    for game in [&mut normal, &mut tuned] {
+        game.handle_player_command(&id, &thrust());
+        game.tick();
+    }
+
+    assert_eq!(tuned.config().max_asteroids, 8);
+    assert!(tuned.drain_events().contains(&GameEvent::ConfigReloaded { changed }));
+
+    let (x0, y0) = normal.players.get_player(&id).unwrap().position();
+    let (x1, y1) = tuned.players.get_player(&id).unwrap().position();
+    assert_ne!((x0, y0), (x1, y1));
+}
+
+#[test]
+fn restart_only_keys_are_rejected() {
+    let mut game = GameManager::with_seed(3);
+
+    let mut config = game.config().clone();
+    config.tick_rate = 60;
+    config.world_size = 8000;
+    config.ship.deceleration = 1.0;
+
+    let err = game.reload_config(config).unwrap_err();
+    assert!(err.contains("tick_rate"));
+    assert!(err.contains("world_size"));
+
+    game.tick();
+    assert_eq!(game.config(), &GameConfig::default());
+}
+
+#[test]
+fn unchanged_config_is_a_no_op() {
+    let mut game = GameManager::with_seed(3);
+    let config = game.config().clone();
+
+    assert!(game.reload_config(config).unwrap().is_empty());
+    game.tick();
+    assert!(!game.drain_events().iter().any(|e| matches!(e, GameEvent::ConfigReloaded { .. })));
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "metrics")]
+
+use std::time::Duration;
+
+use asteroids_server::entities::hitbox::EntityKind;
+use asteroids_server::metrics::Metrics;
+
+#[test]
+fn renders_prometheus_text() {
+    let metrics = Metrics::new();
+    let budget = Duration::from_millis(31);
+
+    metrics.record_tick(Duration::from_millis(4), budget);
+    metrics.record_tick(Duration::from_millis(40), budget);
+    metrics.set_entities(EntityKind::Player, 3);
+    metrics.set_entities(EntityKind::Asteroid, 12);
+    metrics.set_entities(EntityKind::PowerUp, 2);
+    metrics.set_entities(EntityKind::Ufo, 1);
+    metrics.set_clients(4);
+    metrics.count_message("move");
+    metrics.count_message("move");
+    metrics.count_message("ping");
+
+    let text = metrics.render();
+
+    assert!(text.contains("# TYPE asteroids_tick_duration_seconds histogram"));
+    assert!(text.contains("asteroids_tick_duration_seconds_bucket{le=\"0.005\"} 1"));
+    assert!(text.contains("asteroids_tick_duration_seconds_bucket{le=\"0.05\"} 2"));
+    assert!(text.contains("asteroids_tick_duration_seconds_count 2"));
+    assert!(text.contains("asteroids_tick_overruns_total 1"));
+    assert!(text.contains("asteroids_entities{kind=\"asteroids\"} 12"));
+    assert!(text.contains("asteroids_entities{kind=\"powerups\"} 2"));
+    assert!(text.contains("asteroids_entities{kind=\"ufos\"} 1"));
+    assert!(text.contains("asteroids_entities{kind=\"bullets\"} 0"));
+    assert!(text.contains("asteroids_connected_clients 4"));
+    assert!(text.contains("asteroids_messages_received_total{type=\"move\"} 2"));
+    assert!(text.contains("process_resident_memory_bytes"));
+}
@@ -0,0 +1,159 @@
+use asteroids_server::collections::player_collection::PlayerCollection;
+use asteroids_server::config::GameConfig;
+use asteroids_server::events::{DeathCause, GameEvent};
+use asteroids_server::game::GameManager;
+use asteroids_server::modes::GameMode;
+use asteroids_server::entities::bullet::Bullet;
+use asteroids_server::modes::battle_royale::{BattleRoyale, SafeZone};
+use asteroids_server::modes::deathmatch::{Deathmatch, KILL_POINTS};
+use asteroids_server::systems::movement::MovementSystem;
+use asteroids_server::systems::wrap::WrapSystem;
+use asteroids_server::world::World;
+use uuid::Uuid;
+
+#[test]
+fn survival_puts_everyone_on_one_team_without_friendly_fire() {
+    let mut config = GameConfig::default();
+    config.mode.name = "survival".into();
+    let mut game = GameManager::with_config(config, Some(3));
+    let a = Uuid::new_v4();
+    let b = Uuid::new_v4();
+    game.add_player(&a).unwrap();
+    game.add_player(&b).unwrap();
+
+    assert!(!game.teams.friendly_fire);
+    assert_eq!(game.players.get_player(&a).unwrap().team(), Some(1));
+    assert_eq!(game.players.get_player(&b).unwrap().team(), Some(1));
+}
+
+#[test]
+fn deathmatch_ends_when_score_limit_is_reached() {
+    let mut mode = Deathmatch { score_limit: KILL_POINTS };
+    let mut players = PlayerCollection::seeded(3);
+    let killer = Uuid::new_v4();
+    let victim = Uuid::new_v4();
+    players.add_player(&killer).unwrap();
+
+    assert!(mode.check_round_end(&players).is_none());
+
+    mode.on_kill(&mut players, &victim, Some(&killer));
+    let outcome = mode.check_round_end(&players).unwrap();
+    assert_eq!(outcome.winner, Some(killer));
+}
+
+#[test]
+fn time_attack_ends_round_after_duration() {
+    let mut config = GameConfig::default();
+    config.mode.name = "time_attack".into();
+    config.mode.time_limit_secs = 1;
+    let mut game = GameManager::with_config(config, Some(5));
+    let id = Uuid::new_v4();
+    game.add_player(&id).unwrap();
+    game.drain_events();
+
+    let mut rounds = 0;
+    for _ in 0..32 {
+        game.tick();
+        rounds += game
+            .drain_events()
+            .iter()
+            .filter(|e| matches!(e, GameEvent::RoundEnded { mode: "time_attack", .. }))
+            .count();
+    }
+    assert_eq!(rounds, 1);
+}
+
+#[test]
+fn battle_royale_zone_shrinks_and_kills_outside_ships() {
+    // zona encolhe todo tick e mata no primeiro tick fora
+    let mut config = GameConfig::default();
+    config.mode.zone_shrink_secs = 1.0 / 32.0;
+    config.mode.zone_grace_secs = 1.0 / 32.0;
+    let mut mode = BattleRoyale::new(&config);
+    let mut players = PlayerCollection::seeded(11);
+    let mut events = Vec::new();
+
+    for _ in 0..2 {
+        let id = Uuid::new_v4();
+        players.add_player(&id).unwrap();
+        mode.on_join(players.get_player_mut(&id).unwrap());
+    }
+
+    let start = mode.zone().radius;
+    for _ in 0..32 {
+        mode.on_tick(&mut players, &mut events);
+    }
+
+    assert!(mode.zone().radius < start);
+    assert!(players.len() < 2);
+    assert!(events.iter().any(|e| matches!(e, GameEvent::PlayerKilled { cause: DeathCause::Zone, .. })));
+    assert!(mode.check_round_end(&players).is_some());
+}
+
+#[test]
+fn battle_royale_holds_bullets_at_the_zone_and_the_world_edge() {
+    let zone = SafeZone { x: 1000.0, y: 1000.0, radius: 100.0 };
+    let mut world = World::default();
+    let size = world.size();
+    let inside = Bullet::new(Uuid::from_u128(1), 1095.0, 1000.0, 10.0, 0.0, 60).spawn(&mut world);
+    let outside = Bullet::new(Uuid::from_u128(1), size - 1.0, 10.0, 10.0, 0.0, 60).spawn(&mut world);
+
+    MovementSystem::run(&mut world);
+    WrapSystem::run(&mut world, false, Some(&zone));
+
+    // a de dentro para no limite, sem a velocidade pra fora
+    let transform = world.transforms.get(inside).unwrap();
+    assert!((transform.x - 1100.0).abs() < 0.1);
+    assert!(world.velocities.get(inside).unwrap().vx.abs() < 1e-3);
+    // a de fora não volta pela esquerda
+    assert_eq!(world.transforms.get(outside).unwrap().x, size);
+}
+
+#[test]
+fn battle_royale_forgets_contenders_that_leave() {
+    let mut config = GameConfig::default();
+    config.mode.name = "battle_royale".into();
+    let mut game = GameManager::with_config(config, Some(3));
+    let a = Uuid::new_v4();
+    game.add_player(&a).unwrap();
+    game.remove_player(&a);
+    game.add_player(&a).unwrap();
+
+    // sozinho na sala: ninguém venceu ainda
+    game.tick();
+    assert!(!game.drain_events().iter().any(|e| matches!(e, GameEvent::RoundEnded { .. })));
+    assert_eq!(game.players.len(), 1);
+}
+
+#[test]
+fn battle_royale_forgets_time_outside_when_the_ship_dies() {
+    // zona encolhe todo tick; 1 s (32 ticks) de tolerância fora dela
+    let mut config = GameConfig::default();
+    config.mode.zone_shrink_secs = 1.0 / 32.0;
+    config.mode.zone_grace_secs = 1.0;
+    let mut mode = BattleRoyale::new(&config);
+    let mut players = PlayerCollection::seeded(11);
+    let mut events = Vec::new();
+    let (a, b) = (Uuid::from_u128(1), Uuid::from_u128(2));
+    for id in [a, b] {
+        players.add_player(&id).unwrap();
+        mode.on_join(players.get_player_mut(&id).unwrap());
+        players.place(&id, 3000.0, 3000.0);
+    }
+
+    // 20 ticks fora e morre de tiro; volta com o mesmo id e fica fora de novo
+    players.place(&a, 0.0, 0.0);
+    for _ in 0..20 {
+        mode.on_tick(&mut players, &mut events);
+    }
+    players.kill(&a);
+    mode.on_tick(&mut players, &mut events);
+    players.add_player(&a).unwrap();
+    players.place(&a, 0.0, 0.0);
+    for _ in 0..20 {
+        mode.on_tick(&mut players, &mut events);
+    }
+
+    assert!(!events.iter().any(|e| matches!(e, GameEvent::PlayerKilled { cause: DeathCause::Zone, .. })));
+    assert!(players.is_alive(&a));
+}
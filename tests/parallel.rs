@@ -0,0 +1,57 @@
+use asteroids_server::game::GameManager;
+#[cfg(feature = "parallel")]
+use asteroids_server::networking::router::MovePayload;
+#[cfg(feature = "parallel")]
+use uuid::Uuid;
+
+/// Sala cheia atirando sem parar; devolve todos os snapshots.
+#[cfg(feature = "parallel")]
+fn simulate(parallel: bool, ticks: u32) -> Vec<String> {
+    let mut game = GameManager::with_seed(11);
+    game.set_parallel(parallel);
+    assert_eq!(game.is_parallel(), parallel);
+
+    let ids: Vec<Uuid> = (1..=255).map(Uuid::from_u128).collect();
+    for id in ids.iter() {
+        game.add_player(id).unwrap();
+    }
+
+    (0..ticks)
+        .map(|tick| {
+            for (i, id) in ids.iter().enumerate() {
+                let phase = tick + i as u32 * 3;
+                let command = MovePayload {
+                    thrust: phase % 30 < 20,
+                    left: phase % 50 < 8,
+                    right: phase % 70 > 62,
+                    fire: true,
+                };
+                game.handle_player_command(id, &command);
+            }
+            game.tick();
+            game.get_game_state()
+        })
+        .collect()
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn parallel_tick_matches_serial_byte_for_byte() {
+    let serial = simulate(false, 120);
+    let parallel = simulate(true, 120);
+
+    // teve tiro e morte, senão não prova muita coisa
+    assert!(serial.iter().any(|s| s.contains("player_killed")));
+
+    for (tick, (s, p)) in serial.iter().zip(parallel.iter()).enumerate() {
+        assert_eq!(s, p, "paralelo divergiu no tick {}", tick + 1);
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+#[test]
+fn without_the_feature_tick_stays_serial() {
+    let mut game = GameManager::with_seed(11);
+    game.set_parallel(true);
+    assert!(!game.is_parallel());
+}
@@ -0,0 +1,123 @@
+use asteroids_server::collections::asteroid_collection::AsteroidCollection;
+use asteroids_server::collections::player_collection::PlayerCollection;
+use asteroids_server::config::{GameConfig, PhysicsConfig};
+use asteroids_server::entities::asteroid::AsteroidType;
+use asteroids_server::entities::hitbox::{EntityKind, LAYER_BULLET, LAYER_PLAYER};
+use asteroids_server::events::GameEvent;
+use asteroids_server::systems::collision::CollisionSystem;
+use asteroids_server::types::EntityId;
+use asteroids_server::world::World;
+use asteroids_server::world::components::{Collider, Transform, Velocity};
+use uuid::Uuid;
+
+// asteroide redondo, pra conta dar exata
+fn rock(world: &mut World, x: f32, vx: f32, tier: AsteroidType) -> EntityId {
+    let id = world.spawn(EntityKind::Asteroid);
+    world.transforms.insert(id, Transform::new(x, 1000.0, 0.0));
+    world.velocities.insert(id, Velocity { vx, vy: 0.0 });
+    world.colliders.insert(id, Collider::circle(EntityKind::Asteroid, tier.radius() as f32, LAYER_PLAYER | LAYER_BULLET));
+    world.tiers.insert(id, tier);
+    id
+}
+
+fn ship_at(config: &GameConfig, x: f32, vx: f32) -> (PlayerCollection, Uuid) {
+    let id = Uuid::from_u128(1);
+    let mut players = PlayerCollection::with_config(config, Some(1));
+    players.add_player(&id).unwrap();
+    players.place(&id, x, 1000.0);
+    players.get_player_mut(&id).unwrap().body_mut().1.vx = vx;
+    (players, id)
+}
+
+fn collide(world: &mut World, players: &mut PlayerCollection, physics: Option<&PhysicsConfig>) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    CollisionSystem::run(world, players, &mut AsteroidCollection::seeded(1), true, physics, &mut events);
+    events
+}
+
+#[test]
+fn asteroids_pass_through_each_other_without_physics() {
+    let mut world = World::default();
+    let a = rock(&mut world, 1000.0, 2.0, AsteroidType::SMALL);
+    rock(&mut world, 1020.0, -2.0, AsteroidType::SMALL);
+
+    collide(&mut world, &mut PlayerCollection::new(), None);
+    assert_eq!(world.velocities.get(a), Some(&Velocity { vx: 2.0, vy: 0.0 }));
+}
+
+#[test]
+fn equal_asteroids_swap_velocities_and_stop_overlapping() {
+    let physics = PhysicsConfig { enabled: true, ..PhysicsConfig::default() };
+    let mut world = World::default();
+    let a = rock(&mut world, 1000.0, 2.0, AsteroidType::SMALL);
+    let b = rock(&mut world, 1020.0, -3.0, AsteroidType::SMALL);
+
+    collide(&mut world, &mut PlayerCollection::new(), Some(&physics));
+
+    assert!((world.velocities.get(a).unwrap().vx + 3.0).abs() < 1e-4);
+    assert!((world.velocities.get(b).unwrap().vx - 2.0).abs() < 1e-4);
+
+    // saíram do toque, cada um metade pro seu lado
+    let (xa, xb) = (world.transforms.get(a).unwrap().x, world.transforms.get(b).unwrap().x);
+    assert!(xb - xa >= 29.9 && xb - xa <= 30.1, "distância {}", xb - xa);
+    assert!((xa + xb - 2020.0).abs() < 1e-3);
+}
+
+#[test]
+fn heavier_asteroid_barely_moves() {
+    let physics = PhysicsConfig { enabled: true, ..PhysicsConfig::default() };
+    let mut world = World::default();
+    let big = rock(&mut world, 1000.0, 0.0, AsteroidType::BIG);
+    let small = rock(&mut world, 1045.0, -4.0, AsteroidType::SMALL);
+
+    collide(&mut world, &mut PlayerCollection::new(), Some(&physics));
+
+    let (v_big, v_small) = (world.velocities.get(big).unwrap().vx, world.velocities.get(small).unwrap().vx);
+    assert!(v_big < 0.0 && v_big > -1.5);
+    assert!(v_small > 0.0);
+
+    // momento conservado
+    let before = AsteroidType::SMALL.mass() * -4.0;
+    let after = AsteroidType::BIG.mass() * v_big + AsteroidType::SMALL.mass() * v_small;
+    assert!((before - after).abs() < 1e-3);
+}
+
+#[test]
+fn ship_bump_costs_health_instead_of_life() {
+    let physics = PhysicsConfig { enabled: true, ..PhysicsConfig::default() };
+    let mut world = World::default();
+    let asteroid = rock(&mut world, 1020.0, -1.0, AsteroidType::SMALL);
+    let (mut players, id) = ship_at(&GameConfig::default(), 1000.0, 2.0);
+
+    let events = collide(&mut world, &mut players, Some(&physics));
+
+    let ship = players.get_player(&id).unwrap();
+    assert!(events.is_empty());
+    assert!(world.contains(asteroid));
+    assert!(ship.velocity().vx < 0.0);
+    // aproximação de 3 px/tick
+    let config = GameConfig::default();
+    assert!((ship.health() - (config.ship.health - 3.0 * config.damage.collision)).abs() < 1e-3);
+}
+
+#[test]
+fn ship_dies_when_the_health_runs_out() {
+    let physics = PhysicsConfig { enabled: true, ..PhysicsConfig::default() };
+    let mut config = GameConfig { physics: physics.clone(), ..GameConfig::default() };
+    config.ship.health = 10.0;
+    let mut world = World::default();
+    rock(&mut world, 1020.0, -1.0, AsteroidType::SMALL);
+    let (mut players, id) = ship_at(&config, 1000.0, 2.0);
+
+    let events = collide(&mut world, &mut players, Some(&physics));
+
+    assert!(players.get_player(&id).is_none());
+    assert!(matches!(events[..], [GameEvent::PlayerKilled { killer: None, .. }]));
+}
+
+#[test]
+fn restitution_must_be_a_fraction() {
+    let mut config = GameConfig::default();
+    config.physics.restitution = 1.5;
+    assert!(config.validate().unwrap_err().contains("physics.restitution"));
+}
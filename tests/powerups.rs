@@ -0,0 +1,112 @@
+use asteroids_server::collections::asteroid_collection::AsteroidCollection;
+use asteroids_server::collections::player_collection::PlayerCollection;
+use asteroids_server::collections::powerup_collection::PowerUpCollection;
+use asteroids_server::config::GameConfig;
+use asteroids_server::entities::hitbox::EntityKind;
+use asteroids_server::entities::player::Damage;
+use asteroids_server::entities::powerup::{PowerUp, PowerUpKind};
+use asteroids_server::entities::traits::warp_object::WarpObject;
+use asteroids_server::events::GameEvent;
+use asteroids_server::networking::router::MovePayload;
+use asteroids_server::systems::collision::CollisionSystem;
+use asteroids_server::systems::lifetime::LifetimeSystem;
+use asteroids_server::world::World;
+use uuid::Uuid;
+
+const ID: Uuid = Uuid::from_u128(1);
+
+fn ship(config: &GameConfig) -> PlayerCollection {
+    let mut players = PlayerCollection::with_config(config, Some(9));
+    players.add_player(&ID).unwrap();
+    players
+}
+
+fn fire(players: &mut PlayerCollection) -> usize {
+    players.handle_command(&ID, &MovePayload { thrust: false, left: false, right: false, fire: true });
+    players.update(true).len()
+}
+
+#[test]
+fn items_show_up_on_a_timer_and_expire() {
+    let mut config = GameConfig::default();
+    config.powerups.interval_secs = 1.0;
+    config.powerups.max = 2;
+    config.powerups.lifetime_secs = 4.0;
+    let mut world = World::default();
+    let mut powerups = PowerUpCollection::with_config(&config, Some(3));
+    let mut events = Vec::new();
+
+    for _ in 0..config.ticks(3.0) {
+        powerups.update(&mut world, &mut events);
+        LifetimeSystem::run(&mut world);
+    }
+    // um por segundo, até o limite
+    assert_eq!(world.count(EntityKind::PowerUp), 2);
+    assert_eq!(events.iter().filter(|e| matches!(e, GameEvent::PowerUpSpawned { .. })).count(), 2);
+    assert!(PowerUpCollection::to_json(&world).starts_with("\"PowerUps\":[ {\"id\""));
+
+    for _ in 0..config.ticks(4.0) {
+        LifetimeSystem::run(&mut world);
+    }
+    assert_eq!(world.count(EntityKind::PowerUp), 0);
+}
+
+#[test]
+fn touching_an_item_collects_it() {
+    let config = GameConfig::default();
+    let mut players = ship(&config);
+    let (x, y) = players.get_player(&ID).unwrap().position();
+    let mut world = World::default();
+    let mut events = Vec::new();
+    let item = PowerUpCollection::new().spawn(&mut world, PowerUp::new(x + 15.0, y, PowerUpKind::SpeedBoost, 100), &mut events);
+
+    CollisionSystem::run(&mut world, &mut players, &mut AsteroidCollection::seeded(1), true, None, &mut events);
+
+    assert!(!world.contains(item));
+    assert_eq!(events.last(), Some(&GameEvent::PowerUpCollected { id: item, kind: PowerUpKind::SpeedBoost, by: ID }));
+    let player = players.get_player(&ID).unwrap();
+    assert!(player.has_effect(PowerUpKind::SpeedBoost));
+    assert!(player.to_json().contains("\"effects\": [{\"kind\": \"speed_boost\", \"remaining\": 10}]"));
+}
+
+#[test]
+fn spread_shot_and_rapid_fire_change_the_gun() {
+    let config = GameConfig::default();
+    let cooldown = config.ticks(config.weapons.cannon.cooldown_secs);
+    let mut players = ship(&config);
+
+    // conta os ticks até o próximo disparo e devolve (ticks, balas)
+    let next_shot = |players: &mut PlayerCollection| {
+        (1..).map(|tick| (tick, fire(players))).find(|(_, bullets)| *bullets > 0).unwrap()
+    };
+
+    assert_eq!(next_shot(&mut players), (1, 1));
+    assert_eq!(next_shot(&mut players), (cooldown, 1));
+
+    let player = players.get_player_mut(&ID).unwrap();
+    player.apply_powerup(PowerUpKind::SpreadShot);
+    player.apply_powerup(PowerUpKind::RapidFire);
+    let (ticks, bullets) = next_shot(&mut players);
+    assert_eq!(bullets, 3);
+    assert!(ticks < cooldown);
+    assert!(next_shot(&mut players).0 < cooldown);
+}
+
+#[test]
+fn bonus_shield_soaks_damage_and_leaves_with_the_effect() {
+    let mut config = GameConfig::default();
+    config.powerups.duration_secs = 1.0;
+    let mut players = ship(&config);
+
+    players.get_player_mut(&ID).unwrap().apply_powerup(PowerUpKind::Shield);
+    players.damage(&ID, Damage::Bullet(None, 30.0));
+    let player = players.get_player(&ID).unwrap();
+    assert_eq!((player.shield(), player.health()), (20.0, 100.0));
+
+    for _ in 0..config.ticks(1.0) {
+        players.update(true);
+    }
+    let player = players.get_player(&ID).unwrap();
+    assert!(!player.has_effect(PowerUpKind::Shield));
+    assert_eq!(player.shield(), 0.0);
+}
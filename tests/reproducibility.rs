@@ -1,13 +1,16 @@
+use asteroids_server::entities::hitbox::EntityKind;
 use asteroids_server::game::GameManager;
+use asteroids_server::networking::router::MovePayload;
+use uuid::Uuid;
 
 // Extrai as posições dos asteroides como bits (comparação exata de f32),
-// ordenadas — independe da ordem de iteração do HashMap.
+// ordenadas.
 fn asteroid_positions(game: &GameManager) -> Vec<(u32, u32)> {
     let mut pos: Vec<(u32, u32)> = game
-        .asteroids
-        .get_hitboxes()
-        .iter()
-        .map(|h| (h.pos.x.to_bits(), h.pos.y.to_bits()))
+        .world
+        .ids(EntityKind::Asteroid)
+        .filter_map(|id| game.world.transforms.get(id))
+        .map(|t| (t.x.to_bits(), t.y.to_bits()))
         .collect();
     pos.sort();
     pos
@@ -28,3 +31,60 @@ fn different_seed_gives_different_world() {
 
     assert_ne!(asteroid_positions(&a), asteroid_positions(&b));
 }
+
+/// Roda `ticks` com os mesmos jogadores e comandos e devolve todos os snapshots.
+fn simulate(seed: u64, ticks: u32) -> Vec<String> {
+    let mut game = GameManager::with_seed(seed);
+    let ids: Vec<Uuid> = (1..=6).map(Uuid::from_u128).collect();
+    for id in ids.iter() {
+        game.add_player(id).unwrap();
+    }
+
+    (0..ticks)
+        .map(|tick| {
+            for (i, id) in ids.iter().enumerate() {
+                let phase = tick + i as u32 * 7;
+                let command = MovePayload {
+                    thrust: phase % 40 < 25,
+                    left: phase % 60 < 10,
+                    right: phase % 90 > 80,
+                    fire: true,
+                };
+                game.handle_player_command(id, &command);
+            }
+            game.tick();
+            game.get_game_state()
+        })
+        .collect()
+}
+
+/// Quantos eventos do tipo `kind` saíram em todos os snapshots.
+fn event_count(states: &[String], kind: &str) -> usize {
+    let tag = format!("\"type\":\"{}\"", kind);
+    states.iter().map(|s| s.matches(&tag).count()).sum()
+}
+
+#[test]
+fn same_seed_and_inputs_give_byte_identical_states() {
+    let a = simulate(42, 600);
+    let b = simulate(42, 600);
+
+    for (tick, (a, b)) in a.iter().zip(b.iter()).enumerate() {
+        assert_eq!(a, b, "estado divergiu no tick {}", tick + 1);
+    }
+
+    // a partida da seed 42 é sempre esta: tiro, divisão e morte. Mudou a
+    // simulação de propósito? Atualize os números.
+    assert_eq!(event_count(&a, "bullet_fired"), 296);
+    assert_eq!(event_count(&a, "asteroid_destroyed"), 26);
+    assert_eq!(event_count(&a, "player_killed"), 1);
+}
+
+#[test]
+fn different_seed_with_same_inputs_gives_different_states() {
+    let a = simulate(42, 600);
+    let b = simulate(43, 600);
+
+    assert_ne!(a[0], b[0]);
+    assert_ne!(a.last(), b.last());
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use asteroids_server::game::GameManager;
+use asteroids_server::networking::router::{ClientMessage, Router, WsResponse};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+fn message(json: &str) -> ClientMessage {
+    serde_json::from_str(json).unwrap()
+}
+
+#[tokio::test]
+async fn join_leave_and_play_again_in_same_session() {
+    let game = Arc::new(Mutex::new(GameManager::with_seed(1)));
+    let router = Router::new(game.clone());
+    let session = Uuid::new_v4();
+
+    let reply = router.handle_message(&session, &message(r#"{"action":"connect"}"#)).await;
+    assert!(matches!(reply, WsResponse::Unicast(id, msg) if id == session && msg == format!("connected:{}", session)));
+    assert_eq!(game.lock().await.players.len(), 1);
+
+    let reply = router.handle_message(&session, &message(r#"{"action":"disconnect"}"#)).await;
+    assert!(matches!(reply, WsResponse::Unicast(_, msg) if msg == "disconnected"));
+    assert!(game.lock().await.players.is_empty());
+
+    // "jogar de novo" reaproveita a mesma sessão
+    let reply = router.handle_message(&session, &message(r#"{"action":"connect"}"#)).await;
+    assert!(matches!(reply, WsResponse::Unicast(..)));
+    assert_eq!(game.lock().await.players.len(), 1);
+
+    // entrar duas vezes não cria outra nave
+    let reply = router.handle_message(&session, &message(r#"{"action":"connect"}"#)).await;
+    assert!(matches!(reply, WsResponse::Error(_)));
+    assert_eq!(game.lock().await.players.len(), 1);
+}
@@ -0,0 +1,98 @@
+use asteroids_server::collections::asteroid_collection::AsteroidCollection;
+use asteroids_server::entities::hitbox::{EntityKind, HitBox, Point, Shape, LAYER_BULLET, LAYER_PLAYER};
+use asteroids_server::entities::polygon::Polygon;
+use asteroids_server::events::GameEvent;
+use asteroids_server::entities::asteroid::AsteroidType;
+use asteroids_server::types::EntityId;
+use asteroids_server::world::World;
+
+fn polygon_at(x: f32, y: f32, points: &[(f32, f32)]) -> HitBox {
+    let points: Vec<Point> = points.iter().map(|&(x, y)| Point { x, y }).collect();
+    HitBox {
+        shape: Shape::Polygon(Polygon::new(&points)),
+        ..HitBox::circle(EntityId::default(), EntityKind::Asteroid, (x, y), 0.0, LAYER_BULLET)
+    }
+}
+
+fn circle_at(x: f32, y: f32, radius: f32) -> HitBox {
+    HitBox::circle(EntityId::default(), EntityKind::Bullet, (x, y), radius, LAYER_PLAYER)
+}
+
+// quadrado de lado 20 centrado na origem
+const SQUARE: [(f32, f32); 4] = [(10.0, 10.0), (-10.0, 10.0), (-10.0, -10.0), (10.0, -10.0)];
+
+#[test]
+fn circle_against_polygon_uses_the_outline_not_the_bounding_circle() {
+    let square = polygon_at(0.0, 0.0, &SQUARE);
+
+    assert!(circle_at(0.0, 0.0, 1.0).intersects(&square));
+    assert!(square.intersects(&circle_at(14.0, 0.0, 4.0)));
+    assert!(!square.intersects(&circle_at(14.5, 0.0, 4.0)));
+
+    // perto do canto: dentro do círculo que envolve (raio ~14.1), fora do quadrado
+    assert!(!circle_at(13.0, 13.0, 3.0).intersects(&square));
+}
+
+#[test]
+fn concave_notch_does_not_collide() {
+    // "V" aberto pra direita: o bico (2, 0) fica bem dentro do contorno
+    let notched = polygon_at(0.0, 0.0, &[(20.0, 20.0), (-20.0, 20.0), (-20.0, -20.0), (20.0, -20.0), (2.0, 0.0)]);
+
+    assert!(!circle_at(14.0, 0.0, 3.0).intersects(&notched));
+    assert!(circle_at(-14.0, 0.0, 3.0).intersects(&notched));
+}
+
+#[test]
+fn polygon_against_polygon_separating_axis() {
+    let a = polygon_at(0.0, 0.0, &SQUARE);
+    let diamond = [(12.0, 0.0), (0.0, 12.0), (-12.0, 0.0), (0.0, -12.0)];
+
+    assert!(a.intersects(&polygon_at(15.0, 0.0, &SQUARE)));
+    assert!(!a.intersects(&polygon_at(21.0, 0.0, &SQUARE)));
+
+    // círculos envolventes se cruzam, mas a diagonal separa
+    assert!(!a.intersects(&polygon_at(27.0, 27.0, &diamond)));
+    assert!(a.intersects(&polygon_at(15.0, 15.0, &diamond)));
+}
+
+#[test]
+fn seeded_asteroids_send_their_outline_once_at_spawn() {
+    let spawn = || {
+        let mut world = World::default();
+        let mut events = Vec::new();
+        AsteroidCollection::seeded(4).spawn(&mut world, 500.0, 500.0, AsteroidType::BIG, &mut events);
+        (world, events)
+    };
+    let (world, events) = spawn();
+
+    let shape = match &events[0] {
+        GameEvent::AsteroidSpawned { shape, .. } => *shape,
+        other => panic!("evento inesperado: {:?}", other),
+    };
+    assert!((7..=11).contains(&shape.points().len()));
+    assert!(shape.radius() <= 35.0 * 1.2);
+    assert_eq!(spawn().1, events);
+
+    let event: serde_json::Value = serde_json::from_str(&serde_json::to_string(&events[0]).unwrap()).unwrap();
+    assert_eq!(event["shape"].as_array().unwrap().len(), shape.points().len());
+
+    // o snapshot não repete o contorno; quem chega depois pede a lista
+    assert!(!AsteroidCollection::to_json(&world).contains("points"));
+    let shapes: serde_json::Value = serde_json::from_str(&AsteroidCollection::shapes_json(&world)).unwrap();
+    assert_eq!(shapes["shapes"][0]["points"], event["shape"]);
+}
+
+#[test]
+fn collider_turns_the_outline_with_the_transform() {
+    use asteroids_server::world::components::{Collider, Transform};
+
+    // barra deitada: 40 de largura, 4 de altura
+    let points = [(20.0, 2.0), (-20.0, 2.0), (-20.0, -2.0), (20.0, -2.0)].map(|(x, y)| Point { x, y });
+    let collider = Collider::polygon(EntityKind::Asteroid, Polygon::new(&points), LAYER_BULLET);
+    let above = circle_at(0.0, 15.0, 3.0);
+
+    let flat = collider.hitbox(EntityId::default(), &Transform::new(0.0, 0.0, 0.0), None);
+    let upright = collider.hitbox(EntityId::default(), &Transform::new(0.0, 0.0, std::f32::consts::FRAC_PI_2), None);
+    assert!(!flat.intersects(&above));
+    assert!(upright.intersects(&above));
+}
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use asteroids_server::game::GameManager;
+use asteroids_server::networking::router::Router;
+use asteroids_server::networking::websocket_handler::WebSocketHandler;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn shutdown_stops_the_tick_loop() {
+    let game = Arc::new(Mutex::new(GameManager::with_seed(1)));
+    let server = Arc::new(WebSocketHandler::new(Router::new(game.clone())));
+
+    let tick_loop = tokio::spawn(Arc::clone(&server).start());
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(game.lock().await.tick_count() > 0);
+
+    let notified = server.shutdown(Some("outro-host:8080"), Duration::from_secs(1)).await;
+    assert_eq!(notified, 0);
+    assert!(server.is_shutting_down());
+
+    // termina em no máximo um tick
+    tokio::time::timeout(Duration::from_secs(1), tick_loop).await.unwrap().unwrap();
+}
+
+#[test]
+fn scoreboard_is_sorted_by_score() {
+    let mut game = GameManager::with_seed(1);
+    let (a, b, c) = (Uuid::from_u128(1), Uuid::from_u128(2), Uuid::from_u128(3));
+    for id in [a, b, c] {
+        game.add_player(&id).unwrap();
+    }
+    game.players.add_score(&b, 300);
+    game.players.add_score(&c, 100);
+
+    let order: Vec<_> = game.scoreboard().into_iter().map(|(id, score, _)| (id, score)).collect();
+    assert_eq!(order, vec![(b, 300), (c, 100), (a, 0)]);
+}
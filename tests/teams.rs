@@ -0,0 +1,73 @@
+use asteroids_server::collections::asteroid_collection::AsteroidCollection;
+use asteroids_server::collections::bullet_collection::BulletCollection;
+use asteroids_server::collections::player_collection::PlayerCollection;
+use asteroids_server::collections::team_collection::TeamCollection;
+use asteroids_server::entities::bullet::Bullet;
+use asteroids_server::entities::hitbox::EntityKind;
+use asteroids_server::systems::collision::CollisionSystem;
+use asteroids_server::types::TeamId;
+use asteroids_server::world::World;
+use uuid::Uuid;
+
+// Um jogador e um tiro de outro jogador em cima dele, cada um com seu time.
+fn shot_at_player(victim_team: Option<TeamId>, shooter_team: Option<TeamId>, friendly_fire: bool) -> (PlayerCollection, World) {
+    let mut players = PlayerCollection::seeded(3);
+    let bullets = BulletCollection::new();
+    let mut world = World::default();
+    let mut asteroids = AsteroidCollection::seeded(3);
+
+    let victim = Uuid::new_v4();
+    players.add_player(&victim).unwrap();
+    players.get_player_mut(&victim).unwrap().set_team(victim_team);
+
+    let pos = players.get_hitboxes()[0].pos;
+    let mut bullet = Bullet::new(Uuid::new_v4(), pos.x, pos.y, 0.0, 0.0, 160);
+    bullet.team = shooter_team;
+    bullets.add_bullet(&mut world, bullet);
+
+    CollisionSystem::run(&mut world, &mut players, &mut asteroids, friendly_fire, None, &mut Vec::new());
+    (players, world)
+}
+
+#[test]
+fn bullet_kills_enemy() {
+    let (players, world) = shot_at_player(Some(1), Some(2), false);
+    assert!(players.is_empty());
+    assert!(world.count(EntityKind::Bullet) == 0);
+}
+
+#[test]
+fn bullet_passes_through_ally_without_friendly_fire() {
+    let (players, world) = shot_at_player(Some(1), Some(1), false);
+    assert_eq!(players.len(), 1);
+    assert_eq!(world.count(EntityKind::Bullet), 1);
+}
+
+#[test]
+fn bullet_kills_ally_with_friendly_fire() {
+    let (players, _) = shot_at_player(Some(1), Some(1), true);
+    assert!(players.is_empty());
+}
+
+#[test]
+fn auto_assign_balances_and_scores_add_up() {
+    let teams = TeamCollection::new(2, false);
+    let mut players = PlayerCollection::seeded(4);
+
+    for _ in 0..4 {
+        let id = Uuid::new_v4();
+        players.add_player(&id).unwrap();
+        let team = teams.assign(&players, None);
+        let player = players.get_player_mut(&id).unwrap();
+        player.set_team(team);
+        player.add_score(10);
+    }
+
+    assert_eq!(teams.members(&players, 1), 2);
+    assert_eq!(teams.members(&players, 2), 2);
+    assert_eq!(teams.score(&players, 1), 20);
+
+    // time pedido tem prioridade; time inexistente cai no balanceamento
+    assert_eq!(teams.assign(&players, Some(2)), Some(2));
+    assert_eq!(teams.assign(&players, Some(9)), Some(1));
+}
@@ -0,0 +1,150 @@
+use asteroids_server::collections::asteroid_collection::AsteroidCollection;
+use asteroids_server::collections::bullet_collection::BulletCollection;
+use asteroids_server::collections::player_collection::PlayerCollection;
+use asteroids_server::collections::ufo_collection::UfoCollection;
+use asteroids_server::config::GameConfig;
+use asteroids_server::entities::bullet::Bullet;
+use asteroids_server::entities::hitbox::EntityKind;
+use asteroids_server::entities::weapon::WeaponKind;
+use asteroids_server::events::{DeathCause, GameEvent};
+use asteroids_server::game::GameManager;
+use asteroids_server::modes::UFO_POINTS;
+use asteroids_server::networking::router::MovePayload;
+use asteroids_server::systems::collision::CollisionSystem;
+use asteroids_server::systems::movement::MovementSystem;
+use asteroids_server::world::World;
+use uuid::Uuid;
+
+const NEAR: Uuid = Uuid::from_u128(1);
+const FAR: Uuid = Uuid::from_u128(2);
+
+// disco parado, que atira a cada segundo sem errar
+fn still_config() -> GameConfig {
+    let mut config = GameConfig::default();
+    config.ufos.interval_secs = 0.0;
+    config.ufos.speed = 0.0;
+    config.ufos.fire_interval_secs = 1.0;
+    config.ufos.inaccuracy = 0.0;
+    config
+}
+
+#[test]
+fn saucers_show_up_on_a_timer_up_to_the_max() {
+    let mut config = GameConfig::default();
+    config.ufos.interval_secs = 1.0;
+    config.ufos.max = 2;
+    let mut world = World::default();
+    let mut ufos = UfoCollection::with_config(&config, Some(3));
+    let mut events = Vec::new();
+
+    for _ in 0..config.ticks(3.0) {
+        ufos.update(&mut world, &PlayerCollection::new(), &mut events);
+    }
+    assert_eq!(world.count(EntityKind::Ufo), 2);
+    assert_eq!(events.iter().filter(|e| matches!(e, GameEvent::UfoSpawned { .. })).count(), 2);
+    assert!(UfoCollection::to_json(&world).contains("\"health\": 100"));
+}
+
+#[test]
+fn saucer_shoots_at_the_nearest_ship_in_range() {
+    let config = still_config();
+    let mut players = PlayerCollection::with_config(&config, Some(4));
+    players.add_player(&NEAR).unwrap();
+    players.add_player(&FAR).unwrap();
+    players.place(&NEAR, 1000.0, 1300.0);
+    players.place(&FAR, 1600.0, 1000.0);
+    let mut world = World::default();
+    let mut ufos = UfoCollection::with_config(&config, Some(3));
+    let mut events = Vec::new();
+    ufos.spawn(&mut world, 1000.0, 1000.0, &mut events);
+
+    for _ in 0..config.ticks(1.0) {
+        ufos.update(&mut world, &players, &mut events);
+    }
+    // a nave de baixo está mais perto: o tiro sai reto em +y, sem dono
+    let bullet = world.ids(EntityKind::Bullet).next().expect("disco não atirou");
+    let velocity = world.velocities.get(bullet).unwrap();
+    assert!(velocity.vx.abs() < 1e-3 && velocity.vy > 0.0);
+    assert!(BulletCollection::to_json(&world).contains("\"player_id\": null"));
+    assert!(events.contains(&GameEvent::BulletFired { id: bullet, owner: None }));
+}
+
+#[test]
+fn saucer_shot_kills_with_cause_ufo_and_no_killer() {
+    let config = still_config();
+    let mut players = PlayerCollection::with_config(&config, Some(4));
+    players.add_player(&NEAR).unwrap();
+    players.place(&NEAR, 1000.0, 1050.0);
+    let mut world = World::default();
+    let mut ufos = UfoCollection::with_config(&config, Some(3));
+    let mut asteroids = AsteroidCollection::seeded(1);
+    let mut events = Vec::new();
+    ufos.spawn(&mut world, 1000.0, 1000.0, &mut events);
+
+    for _ in 0..config.ticks(2.0) {
+        ufos.update(&mut world, &players, &mut events);
+        MovementSystem::run(&mut world);
+        CollisionSystem::run(&mut world, &mut players, &mut asteroids, true, None, &mut events);
+    }
+    let killed = events.iter().find_map(|e| match e {
+        GameEvent::PlayerKilled { victim, killer, cause } => Some((*victim, *killer, *cause)),
+        _ => None,
+    });
+    assert_eq!(killed, Some((NEAR, None, DeathCause::Ufo)));
+    // o disco segue no mapa: a bala dele não se acerta
+    assert_eq!(world.count(EntityKind::Ufo), 1);
+}
+
+#[test]
+fn shooting_a_saucer_down_scores_points() {
+    let mut config = still_config();
+    config.ufos.fire_interval_secs = 60.0;
+    let mut game = GameManager::with_config(config, Some(5));
+    game.clear_field();
+    game.add_player(&NEAR).unwrap();
+    game.players.place(&NEAR, 1000.0, 1000.0);
+    let mut events = Vec::new();
+    let ufo = game.ufos.spawn(&mut game.world, 1100.0, 1000.0, &mut events);
+    game.drain_events();
+
+    game.handle_player_command(&NEAR, &MovePayload { thrust: false, left: false, right: false, fire: true });
+    let mut destroyed = Vec::new();
+    for _ in 0..30 {
+        game.tick();
+        destroyed.extend(game.drain_events().into_iter().filter(|e| matches!(e, GameEvent::UfoDestroyed { .. })));
+    }
+    assert_eq!(destroyed.len(), 1);
+    assert!(matches!(destroyed[0], GameEvent::UfoDestroyed { id, by: Some(by) } if id == ufo && by == NEAR));
+    assert_eq!(game.world.count(EntityKind::Ufo), 0);
+    let (_, score, _) = game.scoreboard().into_iter().find(|(id, ..)| *id == NEAR).unwrap();
+    assert_eq!(score, UFO_POINTS);
+}
+
+#[test]
+fn spent_bullet_is_not_taken_for_a_saucer_shot() {
+    let config = GameConfig::default();
+    let mut players = PlayerCollection::with_config(&config, Some(4));
+    players.add_player(&NEAR).unwrap();
+    players.add_player(&FAR).unwrap();
+    players.place(&NEAR, 1000.0, 995.0);
+    players.place(&FAR, 1000.0, 1005.0);
+    let mut world = World::default();
+    let mut asteroids = AsteroidCollection::seeded(1);
+    let mut events = Vec::new();
+
+    // uma bala só, à queima-roupa, encostando nas duas naves
+    let shooter = Uuid::from_u128(3);
+    Bullet::new(shooter, 1000.0, 1000.0, 0.0, 0.0, 10).with_weapon(WeaponKind::Cannon, 100.0, 0.0).spawn(&mut world);
+    CollisionSystem::run(&mut world, &mut players, &mut asteroids, true, None, &mut events);
+
+    let killed: Vec<_> = events
+        .iter()
+        .filter_map(|e| match e {
+            GameEvent::PlayerKilled { killer, cause, .. } => Some((*killer, *cause)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(killed, vec![(Some(shooter), DeathCause::Bullet)]);
+    let survivor = [NEAR, FAR].into_iter().find_map(|id| players.get_player(&id)).expect("as duas morreram");
+    assert_eq!(survivor.health(), 100.0);
+}
@@ -0,0 +1,66 @@
+use asteroids_server::collections::asteroid_collection::AsteroidCollection;
+use asteroids_server::entities::hitbox::EntityKind;
+use asteroids_server::events::GameEvent;
+use asteroids_server::config::GameConfig;
+use asteroids_server::game::GameManager;
+use asteroids_server::systems::waves::{DifficultyCurve, WaveDirector};
+use asteroids_server::world::World;
+use uuid::Uuid;
+
+#[test]
+fn curve_grows_with_wave_and_players() {
+    let curve = DifficultyCurve::default();
+
+    assert_eq!(curve.asteroid_count(1, 0), curve.base_asteroids);
+    assert!(curve.asteroid_count(2, 0) > curve.asteroid_count(1, 0));
+    assert!(curve.asteroid_count(1, 10) > curve.asteroid_count(1, 0));
+
+    assert_eq!(curve.speed_scale(1), 1.0);
+    assert!(curve.speed_scale(5) > curve.speed_scale(4));
+    assert_eq!(curve.speed_scale(1000), curve.max_speed_scale);
+}
+
+#[test]
+fn next_wave_starts_after_field_is_cleared_and_intermission() {
+    let curve = DifficultyCurve { intermission_secs: 0.0, ..DifficultyCurve::default() };
+    let mut director = WaveDirector::new(curve.clone(), 32);
+    let mut asteroids = AsteroidCollection::seeded(9);
+    let mut world = World::default();
+    let mut events = Vec::new();
+
+    director.start_next_wave(&mut asteroids, &mut world, 0, &mut events);
+    assert_eq!(director.wave(), 1);
+    assert_eq!(world.count(EntityKind::Asteroid) as u32, curve.base_asteroids);
+
+    // campo ainda cheio: nada muda
+    director.update(&mut asteroids, &mut world, 0, &mut events);
+    assert_eq!(director.wave(), 1);
+
+    world.clear(EntityKind::Asteroid);
+    events.clear();
+    director.update(&mut asteroids, &mut world, 4, &mut events);
+
+    assert_eq!(director.wave(), 2);
+    assert_eq!(world.count(EntityKind::Asteroid) as u32, curve.asteroid_count(2, 4));
+    assert!(events.contains(&GameEvent::WaveStarted { wave: 2, asteroids: curve.asteroid_count(2, 4) }));
+}
+
+#[test]
+fn ships_waiting_to_respawn_do_not_size_the_wave() {
+    let mut config = GameConfig::default();
+    config.waves.intermission_secs = 0.0;
+    config.waves.asteroids_per_player = 2.0;
+    config.ship.respawn_secs = 60.0;
+    let mut game = GameManager::with_config(config.clone(), Some(4));
+    for n in 1..=4 {
+        game.add_player(&Uuid::from_u128(n)).unwrap();
+    }
+    game.players.kill(&Uuid::from_u128(1));
+    game.players.kill(&Uuid::from_u128(2));
+    game.clear_field();
+    game.drain_events();
+
+    game.tick();
+    let expected = config.waves.asteroid_count(2, 2);
+    assert!(game.drain_events().contains(&GameEvent::WaveStarted { wave: 2, asteroids: expected }));
+}
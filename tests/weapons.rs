@@ -0,0 +1,196 @@
+use asteroids_server::collections::asteroid_collection::AsteroidCollection;
+use asteroids_server::collections::bullet_collection::BulletCollection;
+use asteroids_server::collections::player_collection::PlayerCollection;
+use asteroids_server::config::{GameConfig, WeaponLimit};
+use asteroids_server::entities::asteroid::AsteroidType;
+use asteroids_server::entities::bullet::Bullet;
+use asteroids_server::entities::hitbox::{EntityKind, Point, Shape};
+use asteroids_server::entities::polygon::Polygon;
+use asteroids_server::entities::weapon::WeaponKind;
+use asteroids_server::events::GameEvent;
+use asteroids_server::networking::router::MovePayload;
+use asteroids_server::systems::collision::CollisionSystem;
+use asteroids_server::systems::hitscan::HitscanSystem;
+use asteroids_server::systems::homing::HomingSystem;
+use asteroids_server::systems::movement::MovementSystem;
+use asteroids_server::world::World;
+use asteroids_server::world::components::Velocity;
+use uuid::Uuid;
+
+const SHOOTER: Uuid = Uuid::from_u128(1);
+const TARGET: Uuid = Uuid::from_u128(2);
+
+// atirador em (1000, 1000) olhando pra +x, alvo em (x, y)
+fn duel(config: &GameConfig, target: (f32, f32)) -> PlayerCollection {
+    let mut players = PlayerCollection::with_config(config, Some(4));
+    for (id, (x, y)) in [(SHOOTER, (1000.0, 1000.0)), (TARGET, target)] {
+        players.add_player(&id).unwrap();
+        players.place(&id, x, y);
+    }
+    players
+}
+
+// segura o gatilho até sair um disparo; devolve (ticks, balas)
+fn next_shot(players: &mut PlayerCollection) -> (u32, Vec<Bullet>) {
+    (1..200)
+        .find_map(|tick| {
+            players.handle_command(&SHOOTER, &MovePayload { thrust: false, left: false, right: false, fire: true });
+            let bullets = players.update(true);
+            (!bullets.is_empty()).then_some((tick, bullets))
+        })
+        .expect("arma não disparou")
+}
+
+#[test]
+fn laser_hits_the_first_thing_in_line_at_once() {
+    let mut config = GameConfig::default();
+    config.weapons.laser.damage = 40.0;
+    let mut players = duel(&config, (1300.0, 1000.0));
+    players.choose_weapon(&SHOOTER, WeaponKind::Laser);
+    let mut world = World::default();
+    let mut asteroids = AsteroidCollection::seeded(1);
+    let mut events = Vec::new();
+    asteroids.spawn(&mut world, 1150.0, 1000.0, AsteroidType::SMALL, &mut events);
+    events.clear();
+
+    // o asteroide no meio do caminho leva o primeiro raio
+    let (_, shots) = next_shot(&mut players);
+    HitscanSystem::run(&mut world, &mut players, &mut asteroids, true, shots, &mut events);
+    assert_eq!(world.count(EntityKind::Asteroid), 0);
+    let Some(GameEvent::LaserFired { length, .. }) = events.first() else { panic!("sem laser: {:?}", events) };
+    assert!(*length < 150.0);
+    assert_eq!(players.get_player(&TARGET).unwrap().health(), 100.0);
+
+    // caminho livre: a nave, 300 px à frente, leva no mesmo tick do disparo
+    let (_, shots) = next_shot(&mut players);
+    HitscanSystem::run(&mut world, &mut players, &mut asteroids, true, shots, &mut events);
+    assert_eq!(players.get_player(&TARGET).unwrap().health(), 60.0);
+}
+
+#[test]
+fn laser_passes_beside_the_asteroid_outline() {
+    let mut config = GameConfig::default();
+    config.weapons.laser.damage = 40.0;
+    let mut players = duel(&config, (1300.0, 1000.0));
+    players.choose_weapon(&SHOOTER, WeaponKind::Laser);
+    let mut world = World::default();
+    let mut asteroids = AsteroidCollection::seeded(1);
+    let mut events = Vec::new();
+    asteroids.spawn(&mut world, 1150.0, 1012.0, AsteroidType::SMALL, &mut events);
+    events.clear();
+
+    // quadrado de lado 20: o raio em y = 1000 corta o círculo que o envolve
+    // (raio ~14), mas passa 2 px abaixo da aresta
+    let asteroid = world.ids(EntityKind::Asteroid).next().unwrap();
+    let square: Vec<Point> = [(10.0, 10.0), (-10.0, 10.0), (-10.0, -10.0), (10.0, -10.0)].iter().map(|&(x, y)| Point { x, y }).collect();
+    world.colliders.get_mut(asteroid).unwrap().shape = Shape::Polygon(Polygon::new(&square));
+    world.transforms.get_mut(asteroid).unwrap().angle = 0.0;
+
+    let (_, shots) = next_shot(&mut players);
+    HitscanSystem::run(&mut world, &mut players, &mut asteroids, true, shots, &mut events);
+    assert_eq!(world.count(EntityKind::Asteroid), 1);
+    assert_eq!(players.get_player(&TARGET).unwrap().health(), 60.0);
+}
+
+#[test]
+fn mine_stays_put_and_missile_turns_toward_the_enemy() {
+    let config = GameConfig::default();
+    let mut players = duel(&config, (1000.0, 1400.0));
+    let mut world = World::default();
+    let bullets = BulletCollection::new();
+
+    players.get_player_mut(&SHOOTER).unwrap().body_mut().1.vx = 5.0;
+    players.choose_weapon(&SHOOTER, WeaponKind::Mine);
+    let (_, shots) = next_shot(&mut players);
+    let mine = bullets.add_bullets(&mut world, shots)[0];
+    let before = *world.transforms.get(mine).unwrap();
+    MovementSystem::run(&mut world);
+    assert_eq!(world.transforms.get(mine), Some(&before));
+
+    players.choose_weapon(&SHOOTER, WeaponKind::Missile);
+    let (_, shots) = next_shot(&mut players);
+    let missile = bullets.add_bullets(&mut world, shots)[0];
+    let speed = world.velocities.get(missile).unwrap().speed();
+    for _ in 0..5 {
+        HomingSystem::run(&mut world, &players, true);
+        MovementSystem::run(&mut world);
+    }
+    // alvo embaixo (+y): o míssil vira pra ele sem mudar de velocidade
+    let velocity = world.velocities.get(missile).unwrap();
+    assert!(velocity.vy > 0.0);
+    assert!((velocity.speed() - speed).abs() < 1e-3);
+    assert!(world.transforms.get(missile).unwrap().angle > 0.0);
+}
+
+#[test]
+fn heat_locks_the_gun_until_it_cools_down() {
+    let mut config = GameConfig::default();
+    config.weapons.limit = WeaponLimit::Heat;
+    config.weapons.cooling = 32.0; // 1 por tick
+    config.weapons.cannon.heat = 60.0;
+    config.weapons.cannon.cooldown_secs = 0.0;
+    let mut players = duel(&config, (2000.0, 2000.0));
+
+    assert_eq!(next_shot(&mut players).0, 1);
+    assert_eq!(next_shot(&mut players).0, 1);
+    // chegou a 100: só volta a atirar fria
+    let (ticks, _) = next_shot(&mut players);
+    assert!(ticks >= 99, "voltou em {} ticks", ticks);
+    assert!(players.get_player(&SHOOTER).unwrap().to_json().contains("\"kind\": \"cannon\", \"heat\": 60"));
+}
+
+#[test]
+fn empty_magazine_reloads_after_a_while() {
+    let mut config = GameConfig::default();
+    config.weapons.limit = WeaponLimit::Ammo;
+    config.weapons.reload_secs = 1.0;
+    config.weapons.cannon.ammo = 2;
+    config.weapons.cannon.cooldown_secs = 0.0;
+    let mut players = duel(&config, (2000.0, 2000.0));
+
+    assert_eq!(next_shot(&mut players).0, 1);
+    assert_eq!(next_shot(&mut players).0, 1);
+    assert_eq!(players.get_player(&SHOOTER).unwrap().weapon().ammo(), 0);
+    assert_eq!(next_shot(&mut players).0, config.ticks(1.0));
+    assert_eq!(players.get_player(&SHOOTER).unwrap().weapon().ammo(), 1);
+}
+
+#[test]
+fn switching_weapons_does_not_refill_the_magazine() {
+    let mut config = GameConfig::default();
+    config.weapons.limit = WeaponLimit::Ammo;
+    config.weapons.reload_secs = 2.0;
+    config.weapons.cannon.ammo = 1;
+    config.weapons.cannon.cooldown_secs = 0.0;
+    let mut players = duel(&config, (2000.0, 2000.0));
+
+    assert_eq!(next_shot(&mut players).0, 1);
+    players.choose_weapon(&SHOOTER, WeaponKind::Laser);
+    assert_eq!(players.get_player(&SHOOTER).unwrap().weapon().ammo(), config.weapons.laser.ammo);
+    players.choose_weapon(&SHOOTER, WeaponKind::Cannon);
+
+    // o pente vazio continua vazio e só enche no tempo de recarga
+    assert_eq!(players.get_player(&SHOOTER).unwrap().weapon().ammo(), 0);
+    assert_eq!(next_shot(&mut players).0, config.ticks(2.0));
+}
+
+#[test]
+fn bullets_carry_the_weapon_damage_and_kick_the_ship_back() {
+    let mut config = GameConfig::default();
+    config.weapons.cannon.damage = 30.0;
+    config.weapons.cannon.knockback = 64.0; // 2 px/tick
+    let mut players = duel(&config, (1020.0, 1000.0));
+    let mut world = World::default();
+
+    let (_, shots) = next_shot(&mut players);
+    assert_eq!(players.get_player(&SHOOTER).unwrap().velocity(), Velocity { vx: -2.0, vy: 0.0 });
+    assert_eq!(shots[0].weapon, WeaponKind::Cannon);
+    BulletCollection::new().add_bullets(&mut world, shots);
+    assert!(BulletCollection::to_json(&world).contains("\"weapon\": \"cannon\""));
+
+    // bala em cima do alvo
+    MovementSystem::run(&mut world);
+    let mut events = Vec::new();
+    CollisionSystem::run(&mut world, &mut players, &mut AsteroidCollection::seeded(1), true, None, &mut events);
+    assert_eq!(players.get_player(&TARGET).unwrap().health(), 70.0);
+}
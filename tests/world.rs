@@ -0,0 +1,48 @@
+use asteroids_server::entities::bullet::Bullet;
+use asteroids_server::entities::hitbox::EntityKind;
+use asteroids_server::systems::lifetime::LifetimeSystem;
+use asteroids_server::systems::movement::MovementSystem;
+use asteroids_server::systems::wrap::WrapSystem;
+use asteroids_server::world::World;
+use asteroids_server::world::components::{Transform, Velocity};
+use uuid::Uuid;
+
+#[test]
+fn systems_move_wrap_and_expire_entities() {
+    let mut world = World::default();
+    let size = world.size();
+    let id = Bullet::new(Uuid::from_u128(1), size - 1.0, 10.0, 3.0, 0.0, 2).spawn(&mut world);
+
+    MovementSystem::run(&mut world);
+    WrapSystem::run(&mut world, true, None);
+    LifetimeSystem::run(&mut world);
+
+    // passou da borda direita e voltou pela esquerda
+    let transform = world.transforms.get(id).unwrap();
+    assert_eq!((transform.x, transform.y), (2.0, 10.0));
+
+    LifetimeSystem::run(&mut world);
+    assert!(!world.contains(id));
+    assert!(world.transforms.get(id).is_none());
+    assert_eq!(world.count(EntityKind::Bullet), 0);
+}
+
+#[test]
+fn new_entity_kinds_are_just_components() {
+    let mut world = World::default();
+
+    // sem Lifetime nem Collider: só anda
+    let id = world.spawn(EntityKind::Asteroid);
+    world.transforms.insert(id, Transform::new(100.0, 100.0, 0.0));
+    world.velocities.insert(id, Velocity { vx: 0.0, vy: -5.0 });
+
+    MovementSystem::run(&mut world);
+    LifetimeSystem::run(&mut world);
+
+    assert_eq!(world.transforms.get(id), Some(&Transform::new(100.0, 95.0, 0.0)));
+    assert!(world.hitboxes().is_empty());
+
+    assert!(world.despawn(id));
+    assert!(!world.despawn(id));
+    assert!(world.velocities.get(id).is_none());
+}